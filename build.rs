@@ -0,0 +1,67 @@
+//! Generates the registry from whichever `src/yearYYYY/dayNN.rs` files exist, so adding a day or a year never means
+//! editing it by hand. The modules themselves are declared in the checked-in `src/yearYYYY/mod.rs`, which `new-day`
+//! keeps up to date, so that rustfmt and everything else that follows `mod`s can see them.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let src = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+    println!("cargo:rerun-if-changed={}", src.display());
+
+    let mut years: Vec<(u16, Vec<usize>)> = fs::read_dir(&src)
+        .unwrap()
+        .filter_map(|entry| {
+            let entry = entry.unwrap();
+            let year = entry.file_name().into_string().ok()?.strip_prefix("year")?.parse().ok()?;
+            println!("cargo:rerun-if-changed={}", entry.path().display());
+            Some((year, day_numbers(&entry.path())))
+        })
+        .collect();
+    years.sort_unstable();
+
+    let mut registry = String::new();
+    for (year, numbers) in &years {
+        for number in numbers {
+            writeln!(
+                registry,
+                "        (({}, {}), &year{}::day{:02}::Day{:02}),",
+                year, number, year, number, number
+            )
+            .unwrap();
+        }
+    }
+
+    let out = format!(
+        "/// Every solver, keyed by (year, day).\n\
+         pub fn registry() -> BTreeMap<(u16, usize), &'static dyn Solvifier> {{\n    \
+         let solvers: Vec<((u16, usize), &'static dyn Solvifier)> = vec![\n{}    ];\n    \
+         solvers.into_iter().collect()\n}}\n",
+        registry
+    );
+
+    fs::write(Path::new(&env::var("OUT_DIR").unwrap()).join("registry.rs"), out).unwrap();
+}
+
+fn day_numbers(dir: &Path) -> Vec<usize> {
+    let mut numbers: Vec<usize> = fs::read_dir(dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.unwrap().file_name().into_string().ok()?;
+            let digits = name.strip_prefix("day")?.strip_suffix(".rs")?;
+            (digits.len() == 2).then(|| digits.parse().ok()).flatten()
+        })
+        .collect();
+    numbers.sort_unstable();
+
+    // a year's days are run by index, so there mustn't be any holes
+    for (i, number) in numbers.iter().enumerate() {
+        if *number != i + 1 {
+            panic!("found {}/day{:02}.rs, but not day{:02}.rs", dir.display(), number, i + 1);
+        }
+    }
+
+    numbers
+}
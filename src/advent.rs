@@ -1,27 +1,285 @@
+use crate::alloc::{self, Allocs, PhaseAllocs};
+use crate::answers;
+use crate::generate::Generator;
+use crate::render::{Frames, Render};
+use crate::validate::Grammar;
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt::{self, Display, Formatter};
 use std::fs;
-use std::time::Duration;
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
 
 pub enum Advent {
-    Day(Day, bool),
-    All(bool),
+    Day(Day, Options),
+    All(Part, Input, Options),
+    /// `new-day <N> --name <name>`
+    NewDay(usize, String, Options),
+    /// `validate -d <N>` or `validate -a`, where `None` is every day
+    Validate(Option<usize>, Input, Options),
+    /// `generate <N> --seed <seed> --size <size>`, where the size defaults to the day's own
+    Generate(usize, u64, Option<usize>, Options),
+}
+
+#[derive(Clone)]
+pub struct Options {
+    pub check: bool,
+    pub record: bool,
+    pub bench: Option<usize>,
+    pub format: Format,
+    pub jobs: usize,
+    pub year: u16,
+    /// Reads the day's input from this file instead, or from stdin when it's `-`.
+    pub input_file: Option<String>,
+    /// Stands in for `files/<year>/` when looking up inputs, samples, recorded answers and baselines.
+    pub input_dir: Option<String>,
+    pub params: Vec<ParamOverride>,
+    pub save_baseline: Option<String>,
+    pub compare: Option<String>,
+    /// How many percent slower than the baseline a phase can get before `--compare` fails.
+    pub threshold: f64,
+    /// Re-run the day whenever its source, input or sample changes.
+    pub watch: bool,
+    /// How long each of parse, part 1 and part 2 gets before the day is given up on.
+    pub timeout: Option<Duration>,
+    /// How much of what the solvers `Log` to show, from 0 for none up to 2 for everything.
+    pub verbose: u8,
+    /// Writes frames of the puzzle's state under `<dir>/dayNN/partN/`, for the days that know how to draw themselves.
+    pub render: Option<String>,
+    /// Writes an HTML page of an `-a` run's answers and timings here, with deltas when there's a `--compare` baseline.
+    pub report: Option<String>,
+}
+
+/// Benchmark iterations for `--save-baseline` and `--compare` when `--bench` doesn't say.
+const BASELINE_ITERATIONS: usize = 10;
+
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            check: false,
+            record: false,
+            bench: None,
+            format: Format::Table,
+            jobs: 1,
+            year: crate::DEFAULT_YEAR,
+            input_file: None,
+            input_dir: None,
+            params: Vec::new(),
+            save_baseline: None,
+            compare: None,
+            threshold: DEFAULT_THRESHOLD,
+            watch: false,
+            timeout: None,
+            verbose: 0,
+            render: None,
+            report: None,
+        }
+    }
+}
+
+impl Options {
+    pub fn baseline_path(&self, name: &str) -> String {
+        format!("{}/baselines/{}.txt", year_dir(self), name)
+    }
+
+    fn overrides(&self, number: usize) -> impl Iterator<Item = &ParamOverride> {
+        self.params.iter().filter(move |o| o.day == number)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format!("unknown format {:?}, expected table, json or csv", s)),
+        }
+    }
+}
+
+/// A `--param dayNN.name=value` from the command line.
+#[derive(Clone)]
+pub struct ParamOverride {
+    pub day: usize,
+    pub name: String,
+    pub value: usize,
+}
+
+impl FromStr for ParamOverride {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let malformed = || format!("expected day<N>.<name>=<value>, found {:?}", s);
+
+        let (key, value) = s.split_once('=').ok_or_else(malformed)?;
+        let (day, name) = key.split_once('.').ok_or_else(malformed)?;
+        let day = day.strip_prefix("day").and_then(|n| n.parse().ok()).ok_or_else(malformed)?;
+        // whether the number makes sense is up to the day, so that one that divides by it can turn down a zero
+        let value = value
+            .parse()
+            .map_err(|_| format!("expected a number for {}, found {:?}", key, value))?;
+
+        Ok(ParamOverride {
+            day,
+            name: name.to_string(),
+            value,
+        })
+    }
 }
 
 impl Advent {
     pub fn parse_args() -> Self {
         let mut pargs = pico_args::Arguments::from_env();
 
+        let command = pargs.subcommand().unwrap();
+        let validate = command.as_deref() == Some("validate");
+        let mut generate = None;
+        let new_day = match command.as_deref() {
+            Some("new-day") => Some((
+                pargs.free_from_str::<usize>().expect("new-day needs a day number"),
+                pargs.value_from_str::<_, String>("--name").expect("new-day needs a --name"),
+            )),
+            Some("validate") => None,
+            Some("generate") => {
+                generate = Some((
+                    pargs.free_from_str::<usize>().expect("generate needs a day number"),
+                    pargs.opt_value_from_str("--seed").unwrap().unwrap_or(0),
+                    pargs.opt_value_from_str("--size").unwrap(),
+                ));
+                None
+            }
+            Some(command) => panic!("Unknown command {:?}", command),
+            None => None,
+        };
+
         let day = pargs.opt_value_from_str(["-d", "--day"]).unwrap();
         let part = pargs.opt_value_from_str(["-p", "--part"]).unwrap();
         let all = pargs.contains(["-a", "--all"]);
         let check = pargs.contains(["-c", "--check"]);
+        let record = pargs.contains("--record");
         let sample = pargs.contains(["-s", "--sample"]);
+        let bench = pargs.opt_value_from_str("--bench").unwrap();
+        let format = pargs.opt_value_from_str("--format").unwrap().unwrap_or(Format::Table);
+        let jobs = pargs.opt_value_from_str(["-j", "--jobs"]).unwrap().unwrap_or(1);
+        let year = pargs.opt_value_from_str(["-y", "--year"]).unwrap().unwrap_or(crate::DEFAULT_YEAR);
+        let input_file = pargs.opt_value_from_str("--input").unwrap();
+        let input_dir = pargs.opt_value_from_str("--input-dir").unwrap();
+        let params: Vec<ParamOverride> = pargs.values_from_str("--param").unwrap_or_else(|e| panic!("--param: {}", e));
+        let save_baseline: Option<String> = pargs.opt_value_from_str("--save-baseline").unwrap();
+        let compare: Option<String> = pargs.opt_value_from_str("--compare").unwrap();
+        let threshold = pargs.opt_value_from_str("--threshold").unwrap().unwrap_or(DEFAULT_THRESHOLD);
+        let watch = pargs.contains("--watch");
+        let timeout = pargs.opt_value_from_str("--timeout").unwrap().map(Duration::from_millis);
+        let mut verbose = 0;
+        while pargs.contains(["-v", "--verbose"]) {
+            verbose += 1;
+        }
+        let render = pargs.opt_value_from_str("--render").unwrap();
+        let report: Option<String> = pargs.opt_value_from_str("--report").unwrap();
+
+        // baselines are made of benchmark medians, so saving or comparing one implies --bench, except that a report
+        // compares a single run's timings against them
+        let compares_in_report = compare.is_some() && report.is_some();
+        let bench = match bench {
+            None if save_baseline.is_some() || (compare.is_some() && !compares_in_report) => Some(BASELINE_ITERATIONS),
+            bench => bench,
+        };
+
+        // new-day is how a year gets its first day, so it's the one thing that works for a year with none
+        if new_day.is_none() && !crate::years().contains(&year) {
+            panic!("No solutions for {}, there are only {:?}", year, crate::years())
+        }
+
+        for o in &params {
+            let solver = crate::day(year, o.day).unwrap_or_else(|| panic!("--param: there's no day {} in {}", o.day, year));
+            if !solver.params().iter().any(|p| p.name == o.name) {
+                let known: Vec<_> = solver.params().iter().map(|p| p.name).collect();
+                panic!("--param: day {} has no parameter {:?}, it has {:?}", o.day, o.name, known)
+            }
+        }
+
+        if bench == Some(0) {
+            panic!("--bench needs at least one iteration")
+        }
+
+        if bench.is_some() && format != Format::Table {
+            panic!("--bench only supports --format table")
+        }
+
+        if watch && (all || bench.is_some() || input_file.as_deref() == Some("-")) {
+            panic!("--watch only works for a single day run with -d, and not with --bench or --input -")
+        }
+
+        if timeout.is_some() && bench.is_some() {
+            panic!("--timeout doesn't apply to --bench")
+        }
 
-        if sample && (check || all) {
-            panic!("Cannot use -s with -c or -a")
+        if render.is_some() && bench.is_some() {
+            panic!("--render doesn't apply to --bench")
         }
 
-        if all {
-            Self::All(check)
+        if validate && (bench.is_some() || watch || record || check || render.is_some() || timeout.is_some() || format != Format::Table) {
+            panic!("validate doesn't run the solvers, so only takes -d, -a, -s, --year, --input, --input-dir and --param")
+        }
+
+        if report.is_some() && (!all || bench.is_some() || validate || generate.is_some()) {
+            panic!("--report only works for a run of -a, and not with --bench or --save-baseline")
+        }
+
+        if all && input_file.is_some() {
+            panic!("--input only works with -d, use --input-dir to run all days from elsewhere")
+        }
+
+        let options = Options {
+            check,
+            record,
+            bench,
+            format,
+            jobs,
+            year,
+            input_file,
+            input_dir,
+            params,
+            save_baseline,
+            compare,
+            threshold,
+            watch,
+            timeout,
+            verbose,
+            render,
+            report,
+        };
+
+        if let Some((number, name)) = new_day {
+            Self::NewDay(number, name, options)
+        } else if let Some((number, seed, size)) = generate {
+            Self::Generate(number, seed, size, options)
+        } else if validate {
+            match (all, day) {
+                (true, _) => Self::Validate(None, Input::new(sample), options),
+                (false, Some(day)) => Self::Validate(Some(day), Input::new(sample), options),
+                (false, None) => panic!("validate needs either -d or -a"),
+            }
+        } else if all {
+            Self::All(Part::new(part), Input::new(sample), options)
         } else if let Some(day) = day {
             Self::Day(
                 Day {
@@ -29,7 +287,7 @@ impl Advent {
                     part: Part::new(part),
                     input: Input::new(sample),
                 },
-                check,
+                options,
             )
         } else {
             panic!("Must provide either -d or -a")
@@ -44,15 +302,32 @@ pub struct Day {
 }
 
 impl Day {
-    fn path(&self) -> String {
+    /// Where the input comes from, where `-` means stdin.
+    pub fn path(&self, options: &Options) -> String {
+        if let Some(file) = &options.input_file {
+            return file.clone();
+        }
+
         let day_str = format!("{:02}", self.number);
         match self.input {
-            Input::Sample => format!("files/{}/{}.txt", "samples", day_str),
-            Input::Real => format!("files/{}/{}.txt", "inputs", day_str),
+            Input::Sample => format!("{}/{}/{}.txt", year_dir(options), "samples", day_str),
+            Input::Real => format!("{}/{}/{}.txt", year_dir(options), "inputs", day_str),
         }
     }
+
+    fn answers_path(&self, options: &Options) -> String {
+        format!("{}/answers/{:02}.txt", year_dir(options), self.number)
+    }
 }
 
+fn year_dir(options: &Options) -> String {
+    match &options.input_dir {
+        Some(dir) => dir.trim_end_matches('/').to_string(),
+        None => format!("files/{}", options.year),
+    }
+}
+
+#[derive(Clone, Copy)]
 pub enum Part {
     One,
     Two,
@@ -70,7 +345,7 @@ impl Part {
     }
 }
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Input {
     Sample,
     Real,
@@ -87,96 +362,814 @@ impl Input {
 }
 
 pub struct Solution {
+    pub number: usize,
     pub parse_duration: Duration,
     pub part_1: Option<(String, Duration)>,
     pub part_2: Option<(String, Duration)>,
+    pub check: Check,
+    pub name: &'static str,
+    /// Only counted when built with the `alloc-stats` feature.
+    pub allocs: Option<PhaseAllocs>,
+    /// Set when a phase ran past `--timeout`, in which case that phase and any after it have no answers.
+    pub timed_out: Option<Error>,
+}
+
+impl Solution {
+    /// The phase that ran past `--timeout`, if one did.
+    pub fn timed_out_in(&self) -> Option<Phase> {
+        match self.timed_out.as_ref().map(|e| &e.kind) {
+            Some(ErrorKind::Timeout { phase, .. }) => Some(*phase),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parsing"),
+            Phase::Part1 => write!(f, "part 1"),
+            Phase::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+/// What a day reports while it runs, so that whoever's waiting on it can tell which phase is taking too long.
+pub enum Progress {
+    Started(Phase),
+    Parsed(Duration),
+    Answered(Phase, String, Duration),
+}
+
+pub enum Check {
+    Unchecked,
+    Passed,
+    Failed(Error),
+    /// There was nothing to run or check, because the sample file is empty.
+    Skipped,
+}
+
+/// What happened when running a day, with enough to describe it even if it never produced a `Solution`.
+pub struct Outcome {
+    pub number: usize,
+    pub name: &'static str,
+    pub result: Result<Solution>,
+}
+
+impl Outcome {
+    /// The error that made this day fail, whether it never finished or finished with the wrong answers.
+    pub fn error(&self) -> Option<&Error> {
+        match &self.result {
+            Err(e)
+            | Ok(Solution {
+                check: Check::Failed(e), ..
+            })
+            | Ok(Solution { timed_out: Some(e), .. }) => Some(e),
+            Ok(_) => None,
+        }
+    }
+}
+
+/// Timings for one phase of a day across every iteration of a benchmark.
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+
+        let n = samples.len();
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = samples.iter().map(|d| (d.as_secs_f64() - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Stats {
+            min: samples[0],
+            median: samples[n / 2],
+            p95: samples[(n * 95).div_ceil(100) - 1],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+pub struct Benchmark {
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part_1: Option<(String, Stats)>,
+    pub part_2: Option<(String, Stats)>,
+    pub name: &'static str,
+}
+
+impl Benchmark {
+    fn new(runs: Vec<Solution>) -> Self {
+        let first = &runs[0];
+        let name = first.name;
+        let answer_1 = first.part_1.as_ref().map(|(answer, _)| answer.clone());
+        let answer_2 = first.part_2.as_ref().map(|(answer, _)| answer.clone());
+
+        let part_stats =
+            |answer: Option<String>, f: fn(&Solution) -> Duration| answer.map(|a| (a, Stats::new(runs.iter().map(f).collect())));
+
+        Benchmark {
+            iterations: runs.len(),
+            parse: Stats::new(runs.iter().map(|run| run.parse_duration).collect()),
+            part_1: part_stats(answer_1, |run| run.part_1.as_ref().unwrap().1),
+            part_2: part_stats(answer_2, |run| run.part_2.as_ref().unwrap().1),
+            name,
+        }
+    }
+}
+
+/// Untimed runs before a benchmark starts measuring, to get the caches and allocator into shape.
+const WARM_UP: usize = 3;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    Io(String),
+    Parse(String),
+    Unsolvable(String),
+    Mismatch { part: u8, expected: String, actual: String },
+    Panic(String),
+    Timeout { phase: Phase, limit: Duration },
+}
+
+/// Anything that stops a day from producing its answers. Solvers fill in the line and column when they know them,
+/// and the runner fills in the day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub kind: ErrorKind,
+    pub day: Option<usize>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl Error {
+    fn new(kind: ErrorKind) -> Self {
+        Error {
+            kind,
+            day: None,
+            line: None,
+            column: None,
+        }
+    }
+
+    pub fn parse(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Parse(message.into()))
+    }
+
+    pub fn io(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Io(message.into()))
+    }
+
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Unsolvable(message.into()))
+    }
+
+    /// Takes zero-based indices, as produced by `enumerate()`, and reports them one-based.
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.line = Some(line + 1);
+        self.column = Some(column + 1);
+        self
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line + 1);
+        self
+    }
+
+    /// Shifts the column right by `offset`, for errors raised while looking at a slice of a line.
+    pub fn shifted(mut self, offset: usize) -> Self {
+        self.column = self.column.map(|c| c + offset);
+        self
+    }
+
+    fn for_day(mut self, day: usize) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        if let Some(line) = self.line {
+            write!(f, "line {}, ", line)?;
+        }
+        if let Some(column) = self.column {
+            write!(f, "column {}, ", column)?;
+        }
+
+        match &self.kind {
+            ErrorKind::Io(message) => write!(f, "I/O error: {}", message),
+            ErrorKind::Parse(message) => write!(f, "bad input: {}", message),
+            ErrorKind::Unsolvable(message) => write!(f, "no solution: {}", message),
+            ErrorKind::Mismatch { part, expected, actual } => {
+                write!(f, "part {} was {}, expected {}", part, actual, expected)
+            }
+            ErrorKind::Panic(message) => write!(f, "panicked: {}", message),
+            ErrorKind::Timeout { phase, limit } => write!(f, "{} took longer than {:?}", phase, limit),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Parses a number, with an error that says what was found instead.
+pub fn parse_num<T: FromStr>(text: &str) -> Result<T> {
+    text.parse()
+        .map_err(|_| Error::parse(format!("expected a number, found {:?}", text)))
+}
+
+/// Where `part` starts within `whole`, for reporting columns of tokens produced by `split` and friends.
+pub fn column_of(whole: &str, part: &str) -> usize {
+    (part.as_ptr() as usize).saturating_sub(whole.as_ptr() as usize)
+}
+
+/// A tunable that differs between the sample and the real input, like the size of the grid, and can be overridden with
+/// `--param`.
+pub struct Param {
     pub name: &'static str,
+    pub sample: usize,
+    pub real: usize,
+}
+
+/// The values of a solver's parameters for one run.
+pub struct Params {
+    values: Vec<(&'static str, usize)>,
+}
+
+impl Params {
+    pub fn new(declared: &[Param], input: Input) -> Self {
+        let values = declared
+            .iter()
+            .map(|p| match input {
+                Input::Sample => (p.name, p.sample),
+                Input::Real => (p.name, p.real),
+            })
+            .collect();
+
+        Params { values }
+    }
+
+    pub fn set(&mut self, name: &str, value: usize) {
+        match self.values.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, v)) => *v = value,
+            None => panic!("no parameter {:?}", name),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> usize {
+        match self.values.iter().find(|(n, _)| *n == name) {
+            Some((_, v)) => *v,
+            None => panic!("no parameter {:?}, did the solver forget to declare it?", name),
+        }
+    }
+}
+
+/// Where a solver sends what it wants to show about its workings: stderr, tagged with the day, and only when `-v` asks
+/// for that much. Frames of its state go to `--render`'s directory instead. It also carries the part's `Budget` for
+/// `step` to check, only because the log is the one thing every part is handed.
+pub struct Log {
+    day: usize,
+    level: u8,
+    frames: Option<Frames>,
+    budget: Budget,
+}
+
+/// How long a part may keep going: a number of steps when fuzzing, and a flag that's set when a `--timeout` gives up on
+/// it. With neither, as in a normal run, it never runs out.
+#[derive(Default)]
+struct Budget {
+    steps: Cell<u64>,
+    limit: Option<u64>,
+    cancel: Option<Arc<AtomicBool>>,
+}
+
+impl Budget {
+    fn spend(&self) -> Result<()> {
+        let steps = self.steps.get() + 1;
+        self.steps.set(steps);
+        if self.cancel.as_ref().is_some_and(|cancel| cancel.load(Ordering::Relaxed)) {
+            return Err(cancelled());
+        }
+        match self.limit {
+            Some(limit) if steps > limit => Err(Error::unsolvable(format!("gave up after {} steps", limit))),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Log {
+    pub fn new(day: usize, level: u8) -> Self {
+        Log {
+            day,
+            level,
+            frames: None,
+            budget: Budget::default(),
+        }
+    }
+
+    /// Writes whatever gets passed to `render` into `dir`, replacing anything already there.
+    pub fn rendering_to(self, dir: impl Into<std::path::PathBuf>) -> Self {
+        Log {
+            frames: Some(Frames::new(self.day, dir)),
+            ..self
+        }
+    }
+
+    /// Makes `step` give up after `limit` steps, for running a part on input that might never let it finish.
+    pub fn with_step_limit(self, limit: u64) -> Self {
+        Log {
+            budget: Budget {
+                limit: Some(limit),
+                ..self.budget
+            },
+            ..self
+        }
+    }
+
+    /// Makes `step` fail once `cancel` is set, for giving up on a part from another thread.
+    pub fn cancelled_by(self, cancel: Arc<AtomicBool>) -> Self {
+        Log {
+            budget: Budget {
+                cancel: Some(cancel),
+                ..self.budget
+            },
+            ..self
+        }
+    }
+
+    /// Counts a step of a part's main loop, or of a loop that only the input's good sense stops, like a program's jump
+    /// backwards. It fails once there's been more of them than the step limit allows, or once the part's been given up
+    /// on, which is how a part that's run out of time gets to stop rather than spinning on in the background.
+    ///
+    /// It writes nothing, whatever the level, and costs an add, a relaxed atomic load and a compare, so it's fine once
+    /// per stone or second or the like, but wants keeping out of the innermost loops.
+    pub fn step(&self) -> Result<()> {
+        self.budget.spend()
+    }
+
+    /// Whether frames are being kept, for skipping the work of building them.
+    pub fn rendering(&self) -> bool {
+        self.frames.is_some()
+    }
+
+    /// Saves a frame as text and as an image, named after `label` and numbered in the order they came.
+    pub fn render(&self, label: &str, frame: &dyn Render) -> Result<()> {
+        match &self.frames {
+            Some(frames) => frames.write(label, frame),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether anything at `level` gets shown, for skipping work that only feeds the log.
+    pub fn enabled(&self, level: u8) -> bool {
+        level <= self.level
+    }
+
+    /// Shown with `-v`, for a line or two of intermediate results.
+    pub fn debug(&self, args: fmt::Arguments) {
+        self.write(1, args);
+    }
+
+    /// Shown with `-vv`, for the bulky things like whole grids.
+    pub fn trace(&self, args: fmt::Arguments) {
+        self.write(2, args);
+    }
+
+    fn write(&self, level: u8, args: fmt::Arguments) {
+        if self.enabled(level) {
+            for line in args.to_string().lines() {
+                eprintln!("[day {}] {}", self.day, line);
+            }
+        }
+    }
 }
 
 pub trait Solver {
     type Input;
 
-    fn parse(&self, input: &str, is_sample: bool) -> Self::Input;
-    fn part_1(&self, input: &mut Self::Input) -> String;
-    fn part_2(&self, input: &mut Self::Input) -> String;
+    fn parse(&self, input: &str, params: &Params) -> Result<Self::Input>;
+    fn part_1(&self, input: &mut Self::Input, log: &Log) -> Result<String>;
+    fn part_2(&self, input: &mut Self::Input, log: &Log) -> Result<String>;
     fn expected(&self) -> (&'static str, &'static str);
+    /// Parts without a sample answer aren't run against the sample, because not every puzzle's sample covers both parts.
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>);
     fn name(&self) -> &'static str;
+
+    fn params(&self) -> &'static [Param] {
+        &[]
+    }
+
+    /// What the input looks like, for `validate` to check it against before `parse` ever sees it.
+    fn grammar(&self) -> Grammar {
+        Grammar::Text
+    }
+
+    /// How to make up inputs for `generate`, for the days that know how.
+    fn generator(&self) -> Option<Generator> {
+        None
+    }
 }
 
-pub trait Solvifier {
-    fn solve(&self, day: Day, check: bool) -> Solution;
+pub trait Solvifier: Sync {
+    fn solve(&self, day: Day, options: &Options) -> Result<Solution> {
+        self.solve_reporting(day, options, &|_| {}, None)
+    }
+    /// Solves the day, telling `report` as each phase starts and ends, and stopping early once `cancel` is set.
+    fn solve_reporting(&self, day: Day, options: &Options, report: &dyn Fn(Progress), cancel: Option<&Arc<AtomicBool>>)
+        -> Result<Solution>;
+    fn bench(&self, day: Day, options: &Options, iterations: usize) -> Result<Benchmark>;
+    /// Everything wrong with the day's input, without solving it. An empty list means it's fine.
+    fn validate(&self, day: &Day, input: &str, options: &Options) -> Vec<Error>;
+    fn generator(&self) -> Option<Generator>;
+    /// Parses `input` and runs the parts on whatever parses, for fuzzing: errors are expected and ignored, panics are
+    /// let through, and each part gives up after `step_limit` steps.
+    fn exercise(&self, number: usize, input: &str, step_limit: u64);
+    fn name(&self) -> &'static str;
+    fn params(&self) -> &'static [Param];
 }
 
 impl<S> Solvifier for S
 where
-    S: Solver,
+    S: Solver + Sync,
 {
-    fn solve(&self, day: Day, check: bool) -> Solution {
-        let input = load_file(&day.path());
-
-        let parse_time = std::time::Instant::now();
-        let mut input = self.parse(&input, day.input == Input::Sample);
-        let parse_elapsed = parse_time.elapsed();
-
-        match day.part {
-            Part::One => {
-                let time = std::time::Instant::now();
-                let part_1 = self.part_1(&mut input);
-                let elapsed = time.elapsed();
-                if check {
-                    let expected_1 = self.expected().0;
-                    assert_eq!(part_1, expected_1, "Part 1 of day {} failed", day.number);
-                }
-
-                Solution {
-                    parse_duration: parse_elapsed,
-                    part_1: Some((part_1, elapsed)),
+    fn solve_reporting(
+        &self,
+        day: Day,
+        options: &Options,
+        report: &dyn Fn(Progress),
+        cancel: Option<&Arc<AtomicBool>>,
+    ) -> Result<Solution> {
+        guarded(day.number, || {
+            let input = load_input(&day.path(options))?;
+            if day.input == Input::Sample && input.trim().is_empty() {
+                return Ok(Solution {
+                    number: day.number,
+                    parse_duration: Duration::ZERO,
+                    part_1: None,
                     part_2: None,
-                    name: self.name(),
-                }
+                    check: Check::Skipped,
+                    name: Solver::name(self),
+                    allocs: None,
+                    timed_out: None,
+                });
             }
-            Part::Two => {
-                let time = std::time::Instant::now();
-                let part_2 = self.part_2(&mut input);
-                let elapsed = time.elapsed();
-                if check {
-                    let expected_2 = self.expected().1;
-                    assert_eq!(part_2, expected_2, "Part 2 of day {} failed", day.number);
-                }
 
-                Solution {
-                    parse_duration: parse_elapsed,
-                    part_1: None,
-                    part_2: Some((part_2, elapsed)),
-                    name: self.name(),
-                }
+            let mut sol = solve(self, &day, &input, options, report, cancel)?;
+            check_answers(self, &day, &input, &mut sol, options)?;
+            Ok(sol)
+        })
+    }
+
+    fn bench(&self, day: Day, options: &Options, iterations: usize) -> Result<Benchmark> {
+        // logging every iteration would drown the results, and time the logging more than the solver
+        let options = &Options {
+            verbose: 0,
+            render: None,
+            ..options.clone()
+        };
+
+        guarded(day.number, || {
+            let input = load_input(&day.path(options))?;
+            if input.trim().is_empty() {
+                return Err(Error::unsolvable(format!(
+                    "{} is empty, so there's nothing to benchmark",
+                    day.path(options)
+                )));
+            }
+
+            for _ in 0..WARM_UP {
+                solve(self, &day, &input, options, &|_| {}, None)?;
+            }
+
+            // re-parse every time, because plenty of solvers mutate their input
+            let mut runs = (0..iterations)
+                .map(|_| solve(self, &day, &input, options, &|_| {}, None))
+                .collect::<Result<Vec<_>>>()?;
+
+            check_answers(self, &day, &input, &mut runs[0], options)?;
+            if let Check::Failed(e) = std::mem::replace(&mut runs[0].check, Check::Unchecked) {
+                return Err(e);
             }
-            Part::Both => {
-                let time_1 = std::time::Instant::now();
-                let part_1 = self.part_1(&mut input);
-                let elapsed_1 = time_1.elapsed();
-
-                let time_2 = std::time::Instant::now();
-                let part_2 = self.part_2(&mut input);
-                let elapsed_2 = time_2.elapsed();
-
-                if check {
-                    let (expected_1, expected_2) = self.expected();
-                    assert_eq!(part_1, expected_1, "Part 1 of day {} failed", day.number);
-                    assert_eq!(part_2, expected_2, "Part 2 of day {} failed", day.number);
-                }
 
-                Solution {
-                    parse_duration: parse_elapsed,
-                    part_1: Some((part_1, elapsed_1)),
-                    part_2: Some((part_2, elapsed_2)),
-                    name: self.name(),
+            Ok(Benchmark::new(runs))
+        })
+    }
+
+    fn validate(&self, day: &Day, input: &str, options: &Options) -> Vec<Error> {
+        let problems = self.grammar().check(input);
+        if !problems.is_empty() {
+            return problems.into_iter().map(|e| e.for_day(day.number)).collect();
+        }
+
+        // the grammar can't say everything, like that a coordinate is inside the grid, so let parse have its say too
+        let mut params = Params::new(self.params(), day.input);
+        for o in options.overrides(day.number) {
+            params.set(&o.name, o.value);
+        }
+        match guarded(day.number, || self.parse(input, &params).map(|_| ())) {
+            Ok(()) => Vec::new(),
+            Err(e) => vec![e],
+        }
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Solver::generator(self)
+    }
+
+    fn exercise(&self, number: usize, input: &str, step_limit: u64) {
+        // the sample's parameters, since the made-up inputs are small
+        let params = Params::new(self.params(), Input::Sample);
+        let Ok(mut parsed) = self.parse(input, &params) else {
+            return;
+        };
+
+        let log = || Log::new(number, 0).with_step_limit(step_limit);
+        if self.part_1(&mut parsed, &log()).is_ok() {
+            let _ = self.part_2(&mut parsed, &log());
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        Solver::name(self)
+    }
+
+    fn params(&self) -> &'static [Param] {
+        Solver::params(self)
+    }
+}
+
+/// Solves the day on a thread of its own when there's a `--timeout`, giving up on it when a phase runs past the limit
+/// and keeping whatever it had answered by then. Threads can't be stopped from outside, so a day that's given up on is
+/// asked to stop instead: it won't start another phase, and a part stops at its next `Log::step`, so that it isn't left
+/// taking time from the days after it.
+pub fn solve_within(solver: &'static dyn Solvifier, day: Day, options: &Options) -> Result<Solution> {
+    let Some(limit) = options.timeout else {
+        return solver.solve(day, options);
+    };
+
+    enum Message {
+        Progress(Progress),
+        Done(Box<Result<Solution>>),
+    }
+
+    let number = day.number;
+    let (tx, rx) = mpsc::channel();
+    let worker_options = options.clone();
+    let cancel = Arc::new(AtomicBool::new(false));
+    let worker_cancel = cancel.clone();
+    thread::Builder::new()
+        .name(format!("day {}", number))
+        .spawn(move || {
+            let report = |progress| {
+                let _ = tx.send(Message::Progress(progress));
+            };
+            let result = solver.solve_reporting(day, &worker_options, &report, Some(&worker_cancel));
+            let _ = tx.send(Message::Done(Box::new(result)));
+        })
+        .map_err(|e| Error::io(format!("couldn't start a thread: {}", e)).for_day(number))?;
+
+    let mut sol = Solution {
+        number,
+        parse_duration: Duration::ZERO,
+        part_1: None,
+        part_2: None,
+        check: Check::Unchecked,
+        name: solver.name(),
+        allocs: None,
+        timed_out: None,
+    };
+
+    // the clock only runs from a phase starting to it ending, so that neither starting the thread nor reading the input
+    // nor checking the answers counts against any phase
+    let mut running: Option<(Phase, Instant)> = None;
+    loop {
+        let message = match running {
+            Some((_, deadline)) => rx.recv_timeout(deadline.saturating_duration_since(Instant::now())),
+            None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match message {
+            Ok(Message::Progress(Progress::Started(started))) => running = Some((started, Instant::now() + limit)),
+            Ok(Message::Progress(Progress::Parsed(elapsed))) => {
+                sol.parse_duration = elapsed;
+                running = None;
+            }
+            Ok(Message::Progress(Progress::Answered(phase, answer, elapsed))) => {
+                match phase {
+                    Phase::Part1 => sol.part_1 = Some((answer, elapsed)),
+                    _ => sol.part_2 = Some((answer, elapsed)),
                 }
+                running = None;
+            }
+            Ok(Message::Done(result)) => return *result,
+            Err(RecvTimeoutError::Timeout) => {
+                cancel.store(true, Ordering::Relaxed);
+                let phase = running.map_or(Phase::Parse, |(phase, _)| phase);
+                sol.timed_out = Some(Error::new(ErrorKind::Timeout { phase, limit }).for_day(number));
+                return Ok(sol);
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(Error::new(ErrorKind::Panic("the day's thread went away without an answer".to_string())).for_day(number))
             }
         }
     }
 }
 
-fn load_file(path: &str) -> String {
-    fs::read_to_string(path).expect("Failed to read file")
+fn guarded<T, F: FnOnce() -> Result<T>>(number: usize, f: F) -> Result<T> {
+    // a panicking solver shouldn't take the rest of the run down with it
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(Error::new(ErrorKind::Panic(panic_message(payload)))))
+        .map_err(|e| e.for_day(number))
+}
+
+fn solve<S: Solver>(
+    solver: &S,
+    day: &Day,
+    input: &str,
+    options: &Options,
+    report: &dyn Fn(Progress),
+    cancel: Option<&Arc<AtomicBool>>,
+) -> Result<Solution> {
+    let mut params = Params::new(solver.params(), day.input);
+    for o in options.overrides(day.number) {
+        params.set(&o.name, o.value);
+    }
+
+    report(Progress::Started(Phase::Parse));
+    let ((parsed, parse_elapsed), parse_allocs) = alloc::measure(|| {
+        let parse_time = Instant::now();
+        let parsed = solver.parse(input, &params);
+        (parsed, parse_time.elapsed())
+    });
+    let mut input = parsed?;
+    report(Progress::Parsed(parse_elapsed));
+
+    let (has_sample_1, has_sample_2) = match day.input {
+        Input::Sample => {
+            let (sample_1, sample_2) = solver.expected_sample();
+            (sample_1.is_some(), sample_2.is_some())
+        }
+        Input::Real => (true, true),
+    };
+
+    let run = |phase: Phase, f: &mut dyn FnMut() -> Result<String>| {
+        // a day that's been given up on doesn't start anything new
+        if cancel.is_some_and(|cancel| cancel.load(Ordering::Relaxed)) {
+            return Err(cancelled());
+        }
+        report(Progress::Started(phase));
+        let ((answer, elapsed), allocs) = timed(f)?;
+        report(Progress::Answered(phase, answer.clone(), elapsed));
+        Ok::<_, Error>(((answer, elapsed), allocs))
+    };
+
+    // each part gets a directory of frames of its own
+    let log = |part: usize| {
+        let mut log = Log::new(day.number, options.verbose);
+        if let Some(cancel) = cancel {
+            log = log.cancelled_by(cancel.clone());
+        }
+        match &options.render {
+            Some(dir) => log.rendering_to(format!("{}/day{:02}/part{}", dir, day.number, part)),
+            None => log,
+        }
+    };
+
+    let part_1 = match day.part {
+        Part::One | Part::Both if has_sample_1 => {
+            let log = log(1);
+            Some(run(Phase::Part1, &mut || solver.part_1(&mut input, &log))?)
+        }
+        _ => None,
+    };
+
+    let part_2 = match day.part {
+        Part::Two | Part::Both if has_sample_2 => {
+            let log = log(2);
+            Some(run(Phase::Part2, &mut || solver.part_2(&mut input, &log))?)
+        }
+        _ => None,
+    };
+
+    Ok(Solution {
+        number: day.number,
+        parse_duration: parse_elapsed,
+        part_1: part_1.as_ref().map(|(timed, _)| timed.clone()),
+        part_2: part_2.as_ref().map(|(timed, _)| timed.clone()),
+        check: Check::Unchecked,
+        name: Solver::name(solver),
+        allocs: alloc::ENABLED.then(|| PhaseAllocs {
+            parse: parse_allocs,
+            part_1: part_1.map(|(_, allocs)| allocs),
+            part_2: part_2.map(|(_, allocs)| allocs),
+        }),
+        timed_out: None,
+    })
+}
+
+/// What a day that's been given up on stops with, which nobody sees since it's already been reported as a timeout.
+fn cancelled() -> Error {
+    Error::unsolvable("given up on")
+}
+
+/// Records and/or checks the answers, preferring ones recorded for this exact input over the solver's built-in ones.
+fn check_answers<S: Solver>(solver: &S, day: &Day, input: &str, sol: &mut Solution, options: &Options) -> Result<()> {
+    // the known answers are for the puzzle as written, so there's nothing to compare a variant against
+    if options.overrides(day.number).next().is_some() {
+        return Ok(());
+    }
+
+    let path = day.answers_path(options);
+
+    if options.record {
+        answers::record(&path, input, sol)?;
+    }
+
+    if options.check {
+        let expected = answers::lookup(&path, input)?.unwrap_or_else(|| {
+            let (part_1, part_2) = match day.input {
+                Input::Sample => solver.expected_sample(),
+                Input::Real => {
+                    let (part_1, part_2) = solver.expected();
+                    (Some(part_1), Some(part_2))
+                }
+            };
+
+            answers::Answers {
+                part_1: part_1.map(str::to_string),
+                part_2: part_2.map(str::to_string),
+            }
+        });
+
+        sol.check = match verify(1, &sol.part_1, &expected.part_1).and_then(|_| verify(2, &sol.part_2, &expected.part_2)) {
+            Ok(()) => Check::Passed,
+            Err(e) => Check::Failed(e.for_day(day.number)),
+        };
+    }
+
+    Ok(())
+}
+
+/// Times a part and counts what it allocates.
+fn timed<F: FnOnce() -> Result<String>>(f: F) -> Result<((String, Duration), Allocs)> {
+    let (result, allocs) = alloc::measure(|| {
+        let time = Instant::now();
+        f().map(|answer| (answer, time.elapsed()))
+    });
+    Ok((result?, allocs))
+}
+
+fn verify(part: u8, answer: &Option<(String, Duration)>, expected: &Option<String>) -> Result<()> {
+    match (answer, expected) {
+        (Some((actual, _)), Some(expected)) if actual != expected => Err(Error::new(ErrorKind::Mismatch {
+            part,
+            expected: expected.clone(),
+            actual: actual.clone(),
+        })),
+        _ => Ok(()),
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+pub fn load_input(path: &str) -> Result<String> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| Error::io(format!("stdin: {}", e)))?;
+        return Ok(input);
+    }
+
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => Error::io(format!(
+            "{} doesn't exist; pass --input <path> or --input-dir <dir> if the input lives somewhere else",
+            path
+        )),
+        _ => Error::io(format!("{}: {}", path, e)),
+    })
 }
@@ -0,0 +1,97 @@
+//! An allocator that counts what each thread allocates, installed as the global allocator when the `alloc-stats` feature
+//! is on. The counters are per thread so that days running concurrently under `--jobs` don't pollute each other's.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+pub struct Counting;
+
+thread_local! {
+    static COUNT: Cell<u64> = const { Cell::new(0) };
+    static BYTES: Cell<u64> = const { Cell::new(0) };
+    // signed, because a thread can free memory that another thread allocated
+    static LIVE: Cell<i64> = const { Cell::new(0) };
+    static PEAK: Cell<i64> = const { Cell::new(0) };
+}
+
+/// What a stretch of code allocated: how many allocations, how many bytes in total, and the most it had live at once on
+/// top of whatever was live when it started.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Allocs {
+    pub count: u64,
+    pub bytes: u64,
+    pub peak: u64,
+}
+
+/// Allocation counts for each phase of a solution.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PhaseAllocs {
+    pub parse: Allocs,
+    pub part_1: Option<Allocs>,
+    pub part_2: Option<Allocs>,
+}
+
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// Runs `f`, counting what it allocates on this thread.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Allocs) {
+    let count = COUNT.get();
+    let bytes = BYTES.get();
+    let live = LIVE.get();
+    PEAK.set(live);
+
+    let result = f();
+
+    let allocs = Allocs {
+        count: COUNT.get() - count,
+        bytes: BYTES.get() - bytes,
+        peak: (PEAK.get() - live).max(0) as u64,
+    };
+    (result, allocs)
+}
+
+fn allocated(size: usize) {
+    // try_with, because the thread locals can already be gone while a thread is shutting down
+    let _ = COUNT.try_with(|count| count.set(count.get() + 1));
+    let _ = BYTES.try_with(|bytes| bytes.set(bytes.get() + size as u64));
+    let _ = LIVE.try_with(|live| {
+        live.set(live.get() + size as i64);
+        let _ = PEAK.try_with(|peak| peak.set(peak.get().max(live.get())));
+    });
+}
+
+fn freed(size: usize) {
+    let _ = LIVE.try_with(|live| live.set(live.get() - size as i64));
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        freed(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new
+    }
+}
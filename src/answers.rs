@@ -0,0 +1,91 @@
+//! Known answers for puzzle inputs, so that `--check` works for whoever's inputs are in `files/`. Each day has a file of
+//! tab-separated lines, `<input hash>\t<part 1>\t<part 2>`, where an empty answer means it hasn't been recorded yet.
+
+use crate::advent::{Error, Result, Solution};
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// FNV-1a, because it's tiny and, unlike `DefaultHasher`, guaranteed not to change between Rust releases.
+pub fn hash(input: &str) -> String {
+    let hash = input
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |hash, b| (hash ^ b as u64).wrapping_mul(0x100000001b3));
+    format!("{:016x}", hash)
+}
+
+pub fn lookup(path: &str, input: &str) -> Result<Option<Answers>> {
+    if !Path::new(path).exists() {
+        return Ok(None);
+    }
+
+    let key = hash(input);
+    Ok(read(path)?.into_iter().find(|(hash, _)| *hash == key).map(|(_, answers)| answers))
+}
+
+/// Stores the solution's answers for this input, keeping any previously recorded answer for a part that wasn't run.
+pub fn record(path: &str, input: &str, sol: &Solution) -> Result<()> {
+    let mut entries = if Path::new(path).exists() { read(path)? } else { Vec::new() };
+
+    let key = hash(input);
+    let index = match entries.iter().position(|(hash, _)| *hash == key) {
+        Some(index) => index,
+        None => {
+            entries.push((key, Answers::default()));
+            entries.len() - 1
+        }
+    };
+
+    let answers = &mut entries[index].1;
+    if let Some((answer, _)) = &sol.part_1 {
+        answers.part_1 = Some(answer.clone());
+    }
+    if let Some((answer, _)) = &sol.part_2 {
+        answers.part_2 = Some(answer.clone());
+    }
+
+    let contents: String = entries
+        .iter()
+        .map(|(hash, answers)| {
+            let part_1 = answers.part_1.as_deref().unwrap_or_default();
+            let part_2 = answers.part_2.as_deref().unwrap_or_default();
+            format!("{}\t{}\t{}\n", hash, part_1, part_2)
+        })
+        .collect();
+
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir).map_err(|e| Error::io(format!("{}: {}", dir.display(), e)))?;
+    }
+    fs::write(path, contents).map_err(|e| Error::io(format!("{}: {}", path, e)))
+}
+
+fn read(path: &str) -> Result<Vec<(String, Answers)>> {
+    let contents = fs::read_to_string(path).map_err(|e| Error::io(format!("{}: {}", path, e)))?;
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(y, line)| {
+            let mut fields = line.split('\t');
+            let hash = fields.next().unwrap_or_default().to_string();
+            let (Some(part_1), Some(part_2), None) = (fields.next(), fields.next(), fields.next()) else {
+                return Err(Error::parse(format!("{}: expected <hash>\\t<part 1>\\t<part 2>", path)).at_line(y));
+            };
+
+            let answer = |s: &str| if s.is_empty() { None } else { Some(s.to_string()) };
+            Ok((
+                hash,
+                Answers {
+                    part_1: answer(part_1),
+                    part_2: answer(part_2),
+                },
+            ))
+        })
+        .collect()
+}
@@ -0,0 +1,158 @@
+//! Benchmark medians saved under a name, so a later `--bench` can be compared against them. Each baseline is a file of
+//! tab-separated lines, `<day>\t<parse ns>\t<part 1 ns>\t<part 2 ns>`, where an empty time means the part wasn't run.
+
+use crate::advent::{Benchmark, Error, Phase, Result, Solution};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// Slow-downs smaller than this are noise for the fastest phases, however large they are as a percentage.
+const NOISE_FLOOR: Duration = Duration::from_micros(50);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: usize,
+    pub parse: Duration,
+    pub part_1: Option<Duration>,
+    pub part_2: Option<Duration>,
+}
+
+impl Entry {
+    pub fn new(day: usize, bench: &Benchmark) -> Self {
+        Entry {
+            day,
+            parse: bench.parse.median,
+            part_1: bench.part_1.as_ref().map(|(_, stats)| stats.median),
+            part_2: bench.part_2.as_ref().map(|(_, stats)| stats.median),
+        }
+    }
+
+    /// A single run's timings, for setting against a baseline's medians. There's nothing to set against them when the
+    /// parse ran out of time.
+    pub fn measured(sol: &Solution) -> Option<Self> {
+        if sol.timed_out_in() == Some(Phase::Parse) {
+            return None;
+        }
+
+        Some(Entry {
+            day: sol.number,
+            parse: sol.parse_duration,
+            part_1: sol.part_1.as_ref().map(|(_, elapsed)| *elapsed),
+            part_2: sol.part_2.as_ref().map(|(_, elapsed)| *elapsed),
+        })
+    }
+
+    fn phases(&self) -> [(&'static str, Option<Duration>); 3] {
+        [("parse", Some(self.parse)), ("part 1", self.part_1), ("part 2", self.part_2)]
+    }
+}
+
+/// One phase's median in the baseline and now.
+pub struct Delta {
+    pub day: usize,
+    pub phase: &'static str,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Delta {
+    /// How much slower it got, as a percentage of the baseline; negative when it got faster.
+    pub fn change(&self) -> f64 {
+        if self.before.is_zero() {
+            return 0.0;
+        }
+        (self.after.as_secs_f64() - self.before.as_secs_f64()) / self.before.as_secs_f64() * 100.0
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold && self.after.saturating_sub(self.before) > NOISE_FLOOR
+    }
+}
+
+/// Pairs up the phases that were timed both in the baseline and now.
+pub fn compare(baseline: &[Entry], current: &[Entry]) -> Vec<Delta> {
+    current
+        .iter()
+        .filter_map(|now| baseline.iter().find(|then| then.day == now.day).map(|then| (then, now)))
+        .flat_map(|(then, now)| {
+            then.phases()
+                .into_iter()
+                .zip(now.phases())
+                .filter_map(move |((phase, before), (_, after))| {
+                    Some(Delta {
+                        day: now.day,
+                        phase,
+                        before: before?,
+                        after: after?,
+                    })
+                })
+        })
+        .collect()
+}
+
+pub fn load(path: &str) -> Result<Vec<Entry>> {
+    if !Path::new(path).exists() {
+        return Err(Error::io(format!("{} doesn't exist; save one first with --save-baseline", path)));
+    }
+
+    read(path)
+}
+
+/// Stores the entries, keeping what was saved before for any day that wasn't run this time.
+pub fn save(path: &str, entries: &[Entry]) -> Result<()> {
+    let mut saved = if Path::new(path).exists() { read(path)? } else { Vec::new() };
+    saved.retain(|old| !entries.iter().any(|new| new.day == old.day));
+    saved.extend(entries.iter().cloned());
+    saved.sort_unstable_by_key(|entry| entry.day);
+
+    let nanos = |d: Option<Duration>| d.map(|d| d.as_nanos().to_string()).unwrap_or_default();
+    let contents: String = saved
+        .iter()
+        .map(|entry| {
+            format!(
+                "{}\t{}\t{}\t{}\n",
+                entry.day,
+                entry.parse.as_nanos(),
+                nanos(entry.part_1),
+                nanos(entry.part_2)
+            )
+        })
+        .collect();
+
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir).map_err(|e| Error::io(format!("{}: {}", dir.display(), e)))?;
+    }
+    fs::write(path, contents).map_err(|e| Error::io(format!("{}: {}", path, e)))
+}
+
+fn read(path: &str) -> Result<Vec<Entry>> {
+    let contents = fs::read_to_string(path).map_err(|e| Error::io(format!("{}: {}", path, e)))?;
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(y, line)| {
+            let malformed = || Error::parse(format!("{}: expected <day>\\t<parse>\\t<part 1>\\t<part 2>", path)).at_line(y);
+
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [day, parse, part_1, part_2] = fields[..] else {
+                return Err(malformed());
+            };
+
+            let nanos = |s: &str| -> Result<Option<Duration>> {
+                if s.is_empty() {
+                    return Ok(None);
+                }
+                s.parse().map(|n| Some(Duration::from_nanos(n))).map_err(|_| malformed())
+            };
+
+            Ok(Entry {
+                day: day.parse().map_err(|_| malformed())?,
+                parse: nanos(parse)?.ok_or_else(malformed)?,
+                part_1: nanos(part_1)?,
+                part_2: nanos(part_2)?,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,23 @@
+//! What the cargo-fuzz targets in `fuzz/` run, one target per day: the bytes as the day's input, through `parse` and
+//! then both parts. Only a panic counts as a find, since nearly every input is nonsense that the day ought to turn
+//! down with an error.
+
+/// Steps a part gets before it gives up, counted by [`crate::advent::Log::step`] in the loops that nothing but the
+/// input brings to an end. Plenty for the small inputs the fuzzer makes, and small enough that a hang costs it well
+/// under a second.
+pub const STEP_LIMIT: u64 = 100_000;
+
+/// Feeds `data` to the given day, skipping anything that isn't UTF-8 since no input is anything else.
+pub fn run(year: u16, number: usize, data: &[u8]) {
+    let Some(solver) = crate::day(year, number) else {
+        panic!("no day {} in {}", number, year);
+    };
+    if let Ok(input) = std::str::from_utf8(data) {
+        solver.exercise(number, input, STEP_LIMIT);
+    }
+}
+
+/// The name of a day's target, for `cargo fuzz run`.
+pub fn target_name(year: u16, number: usize) -> String {
+    format!("year{}_day{:02}", year, number)
+}
@@ -0,0 +1,100 @@
+//! `generate`, which makes up inputs in a day's format, for benchmarking on bigger inputs than the real one and for
+//! testing on many small ones. The randomness is our own rather than a crate's, so that a seed gives the same input on
+//! every machine and every version.
+
+use crate::grid::Grid;
+
+/// How a day makes up an input.
+pub struct Generator {
+    /// What `--size` counts, like "lines" or "rows and columns".
+    pub size: &'static str,
+    /// About the size of the real input.
+    pub default_size: usize,
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+/// SplitMix64: tiny, fast, and plenty random for making up puzzles.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "nothing below 0 to choose from");
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// A number in `low..=high`.
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        low + self.below((high - low + 1) as usize) as i64
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// A perfect maze, `true` where it's open, carved by a depth-first walk over the cells at odd coordinates so that
+/// there's exactly one way between any two of them. Both sides are rounded down to odd numbers, at least 3.
+pub fn maze(rng: &mut Rng, width: usize, height: usize) -> Grid<bool> {
+    let odd = |n: usize| (n.max(3) - 1) / 2 * 2 + 1;
+    let mut open = Grid::new(odd(width), odd(height), false);
+
+    let start = open.index_of(1, 1);
+    open[start] = true;
+    let mut stack = vec![start];
+    while let Some(&cell) = stack.last() {
+        let (x, y) = open.xy(cell);
+        let unvisited: Vec<(isize, isize)> = [(0, -2), (0, 2), (-2, 0), (2, 0)]
+            .into_iter()
+            .filter(|&(dx, dy)| {
+                open.at(x as isize + dx, y as isize + dy)
+                    .is_some_and(|next| !open[next] && is_inside(&open, next))
+            })
+            .collect();
+
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        let (dx, dy) = *rng.pick(&unvisited);
+        let wall = open.offset(cell, dx / 2, dy / 2).unwrap();
+        let next = open.offset(cell, dx, dy).unwrap();
+        open[wall] = true;
+        open[next] = true;
+        stack.push(next);
+    }
+
+    open
+}
+
+/// Not on the border, which stays wall all the way round.
+fn is_inside<T>(grid: &Grid<T>, i: usize) -> bool {
+    let (x, y) = grid.xy(i);
+    x > 0 && y > 0 && x < grid.width() - 1 && y < grid.height() - 1
+}
@@ -0,0 +1,336 @@
+//! A rectangle of cells stored row by row, for the days whose input is a map. Cells are addressed by their index into
+//! that row-major order, which is what most of those days were already passing around, with `xy` and `index_of` to
+//! convert when coordinates read better.
+
+use crate::advent::{Error, Result};
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// The most cells a day will make a grid of when a `--param` sets its size, far more than any puzzle needs but few
+/// enough to allocate, since running out of memory aborts the whole run rather than failing the one day.
+pub const MAX_CELLS: usize = 1 << 24;
+
+/// The offsets to all eight surrounding cells, the orthogonal ones first.
+pub const DELTAS_8: [(isize, isize); 8] = [(0, -1), (0, 1), (-1, 0), (1, 0), (-1, -1), (1, -1), (-1, 1), (1, 1)];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Down, Dir::Left, Dir::Right];
+
+    /// The arrows puzzles use for directions, `^`, `v`, `<` and `>`.
+    pub fn from_char(c: char) -> Option<Dir> {
+        match c {
+            '^' => Some(Dir::Up),
+            'v' => Some(Dir::Down),
+            '<' => Some(Dir::Left),
+            '>' => Some(Dir::Right),
+            _ => None,
+        }
+    }
+
+    /// (dx, dy), with y growing downwards as it does in the input.
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Dir::Up => (0, -1),
+            Dir::Down => (0, 1),
+            Dir::Left => (-1, 0),
+            Dir::Right => (1, 0),
+        }
+    }
+
+    pub fn rotate_right(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Right,
+            Dir::Right => Dir::Down,
+            Dir::Down => Dir::Left,
+            Dir::Left => Dir::Up,
+        }
+    }
+
+    pub fn rotate_left(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Left,
+            Dir::Left => Dir::Down,
+            Dir::Down => Dir::Right,
+            Dir::Right => Dir::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Down,
+            Dir::Down => Dir::Up,
+            Dir::Left => Dir::Right,
+            Dir::Right => Dir::Left,
+        }
+    }
+
+    /// Its position in `ALL`, for keeping something per direction in an array.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// How many cells a `width` by `height` grid has, as long as that's no more than `MAX_CELLS`.
+pub fn area(width: usize, height: usize) -> Result<usize> {
+    width
+        .checked_mul(height)
+        .filter(|&cells| cells <= MAX_CELLS)
+        .ok_or_else(|| Error::parse(format!("a {}x{} grid is more than the {} cells allowed", width, height, MAX_CELLS)))
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Sets every cell to `value`, for reusing a grid rather than making a new one.
+    pub fn fill(&mut self, value: T) {
+        self.cells.fill(value);
+    }
+}
+
+impl<T> Grid<T> {
+    /// Reads one cell per character, insisting every row is as wide as the first. `f` gets each character with its
+    /// x and y, and its errors are reported at that spot.
+    pub fn parse(input: &str, mut f: impl FnMut(char, usize, usize) -> Result<T>) -> Result<Self> {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::with_capacity(input.len());
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            let mut found = 0;
+            for (x, c) in line.chars().enumerate() {
+                cells.push(f(c, x, y).map_err(|e| e.at(y, x))?);
+                found += 1;
+            }
+            if found != width {
+                return Err(Error::parse(format!("expected a row {} wide, found {}", width, found)).at_line(y));
+            }
+            height += 1;
+        }
+
+        Ok(Grid { width, height, cells })
+    }
+
+    /// Lays `cells` out in rows of `width`.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells don't make rows of {}",
+            cells.len(),
+            width
+        );
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn index_of(&self, x: usize, y: usize) -> usize {
+        y * self.width + x
+    }
+
+    pub fn xy(&self, i: usize) -> (usize, usize) {
+        (i % self.width, i / self.width)
+    }
+
+    /// The index of (x, y), if that's on the grid.
+    pub fn at(&self, x: isize, y: isize) -> Option<usize> {
+        (x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height).then(|| self.index_of(x as usize, y as usize))
+    }
+
+    /// The cell `dx` across and `dy` down from `i`, unless that's off the edge.
+    pub fn offset(&self, i: usize, dx: isize, dy: isize) -> Option<usize> {
+        let (x, y) = self.xy(i);
+        self.at(x as isize + dx, y as isize + dy)
+    }
+
+    pub fn step(&self, i: usize, dir: Dir) -> Option<usize> {
+        // the hot path for most walks, so it works on the index rather than going through coordinates
+        match dir {
+            Dir::Up => i.checked_sub(self.width),
+            Dir::Down => Some(i + self.width).filter(|&j| j < self.cells.len()),
+            Dir::Left => (!i.is_multiple_of(self.width)).then(|| i - 1),
+            Dir::Right => (!(i + 1).is_multiple_of(self.width)).then_some(i + 1),
+        }
+    }
+
+    /// The orthogonal neighbours that are on the grid, in the order of `Dir::ALL`.
+    pub fn neighbors(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        Dir::ALL.into_iter().filter_map(move |dir| self.step(i, dir))
+    }
+
+    /// All eight neighbours that are on the grid, in the order of `DELTAS_8`.
+    pub fn neighbors_8(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        DELTAS_8.into_iter().filter_map(move |(dx, dy)| self.offset(i, dx, dy))
+    }
+
+    pub fn get(&self, i: usize) -> Option<&T> {
+        self.cells.get(i)
+    }
+
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        self.cells.get_mut(i)
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// The indices of the cells matching `pred`, in reading order.
+    pub fn positions<'a>(&'a self, mut pred: impl FnMut(&T) -> bool + 'a) -> impl Iterator<Item = usize> + 'a {
+        self.cells.iter().enumerate().filter(move |(_, c)| pred(c)).map(|(i, _)| i)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// The same shape of grid, with each cell replaced by `f` of it.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        &self.cells[i]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.cells[i]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 1 2
+    /// 3 4 5
+    fn three_by_two() -> Grid<usize> {
+        Grid::from_cells(3, (0..6).collect())
+    }
+
+    #[test]
+    fn test_neighbors_at_edges_and_corners() {
+        let grid = three_by_two();
+        assert_eq!(grid.neighbors(0).collect::<Vec<_>>(), vec![3, 1]);
+        assert_eq!(grid.neighbors(2).collect::<Vec<_>>(), vec![5, 1]);
+        assert_eq!(grid.neighbors(4).collect::<Vec<_>>(), vec![1, 3, 5]);
+        assert_eq!(grid.neighbors(5).collect::<Vec<_>>(), vec![2, 4]);
+
+        // stepping right off the end of a row mustn't wrap onto the next
+        assert_eq!(grid.step(2, Dir::Right), None);
+        assert_eq!(grid.step(3, Dir::Left), None);
+    }
+
+    #[test]
+    fn test_neighbors_8() {
+        let grid = three_by_two();
+        assert_eq!(grid.neighbors_8(0).collect::<Vec<_>>(), vec![3, 1, 4]);
+        assert_eq!(grid.neighbors_8(1).collect::<Vec<_>>(), vec![4, 0, 2, 3, 5]);
+        assert_eq!(grid.neighbors_8(5).collect::<Vec<_>>(), vec![2, 4, 1]);
+
+        let single = Grid::new(1, 1, ());
+        assert_eq!(single.neighbors_8(0).count(), 0);
+    }
+
+    #[test]
+    fn test_rotation() {
+        for dir in Dir::ALL {
+            assert_eq!(dir.rotate_right().rotate_left(), dir);
+            assert_eq!(dir.rotate_right().rotate_right(), dir.reverse());
+            assert_eq!(dir.rotate_left().rotate_left().rotate_left().rotate_left(), dir);
+        }
+        assert_eq!(Dir::Up.rotate_right(), Dir::Right);
+        assert_eq!(Dir::Up.rotate_left(), Dir::Left);
+    }
+
+    #[test]
+    fn test_display_round_trips_parse() {
+        let text = "#.#\n..#\n";
+        let grid = Grid::parse(text, |c, _, _| Ok(c)).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.to_string(), text);
+    }
+
+    #[test]
+    fn test_area() {
+        assert_eq!(area(101, 103).unwrap(), 10403);
+        assert!(area(100_000, 100_000).is_err());
+        assert!(area(usize::MAX, 2).is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_ragged_rows() {
+        assert!(Grid::parse("##\n#\n", |c, _, _| Ok(c)).is_err());
+    }
+}
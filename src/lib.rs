@@ -0,0 +1,50 @@
+//! Advent of Code, 2024 and onwards. Each day is a [`advent::Solver`]; [`registry`] holds them all by year and day, and
+//! is the way in for anything else that wants to run them.
+
+pub mod advent;
+pub mod alloc;
+mod answers;
+pub mod baseline;
+pub mod fuzz;
+pub mod generate;
+pub mod grid;
+pub mod output;
+pub mod render;
+pub mod report;
+pub mod scaffold;
+pub mod search;
+pub mod validate;
+pub mod year2024;
+
+use crate::advent::Solvifier;
+use std::collections::BTreeMap;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: alloc::Counting = alloc::Counting;
+
+// `registry()`, generated by build.rs from the `yearYYYY/dayNN.rs` files in src/
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+/// The year the runner uses when it isn't given `--year`.
+pub const DEFAULT_YEAR: u16 = 2024;
+
+/// Every year with at least one day.
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = registry().keys().map(|(year, _)| *year).collect();
+    years.dedup();
+    years
+}
+
+/// A year's solvers, in order.
+pub fn days(year: u16) -> Vec<&'static dyn Solvifier> {
+    registry()
+        .range((year, 0)..=(year, usize::MAX))
+        .map(|(_, solver)| *solver)
+        .collect()
+}
+
+/// The solver for a given day, counting from 1.
+pub fn day(year: u16, number: usize) -> Option<&'static dyn Solvifier> {
+    registry().get(&(year, number)).copied()
+}
@@ -1,134 +1,584 @@
-pub mod advent;
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
-
-use crate::advent::{Advent, Solution, Solvifier};
-use advent::Day;
-use prettytable::{format, row, Table};
-use std::collections::BTreeMap;
+use advent_2024::advent::{self, Advent, Check, Day, Error, Format, Input, Options, Outcome, Part, Phase, Solution, Solvifier, Stats};
+use advent_2024::alloc::{Allocs, PhaseAllocs};
+use advent_2024::baseline::{self, Delta, Entry};
+use advent_2024::generate::Rng;
+use advent_2024::report::{self, Comparison};
+use advent_2024::{days, output, scaffold, validate};
+use prettytable::{format, row, Cell, Row, Table};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
 use std::time::Duration;
 
+mod watch;
+
 fn main() {
     match Advent::parse_args() {
-        Advent::Day(day, check) => run_one(day, check),
-        Advent::All(check) => run_all(check),
+        Advent::Day(day, options) if options.watch => watch::watch(day, &options),
+        Advent::Day(day, options) => match options.bench {
+            Some(iterations) => bench_one(day, &options, iterations),
+            None => run_one(day, &options),
+        },
+        Advent::All(part, input, options) => match options.bench {
+            Some(iterations) => bench_all(part, input, &options, iterations),
+            None => run_all(part, input, &options),
+        },
+        Advent::NewDay(number, name, options) => match scaffold::new_day(number, &name, &options) {
+            Ok(created) => {
+                for path in created {
+                    println!("Created {}", path);
+                }
+            }
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        },
+        Advent::Validate(number, input, options) => validate_days(number, input, &options),
+        Advent::Generate(number, seed, size, options) => generate(number, seed, size, &options),
     }
 }
 
-fn run_all(check: bool) {
-    let mut times: BTreeMap<usize, Solution> = BTreeMap::new();
-    for (i, solver) in days().iter().enumerate() {
-        let number = i + 1;
+/// Prints a made-up input for the day, the same one every time for the same seed and size.
+fn generate(number: usize, seed: u64, size: Option<usize>, options: &Options) {
+    let solver = advent_2024::day(options.year, number).expect("Day not found");
+    let Some(generator) = solver.generator() else {
+        eprintln!("Error: day {} doesn't know how to make up inputs", number);
+        process::exit(1);
+    };
+
+    let size = size.unwrap_or(generator.default_size);
+    let mut rng = Rng::new(seed);
+    print!("{}", (generator.generate)(&mut rng, size));
+    eprintln!("Generated day {} with seed {} and {} {}", number, seed, size, generator.size);
+}
+
+/// Checks inputs without solving them, listing every problem found and failing if there were any.
+fn validate_days(number: Option<usize>, input: Input, options: &Options) {
+    let numbers: Vec<usize> = match number {
+        Some(number) => vec![number],
+        None => (1..=days(options.year).len()).collect(),
+    };
 
+    let mut failed = false;
+    for number in numbers {
+        let solver = advent_2024::day(options.year, number).expect("Day not found");
         let day = Day {
             number,
-            part: advent::Part::Both,
-            input: advent::Input::Real,
+            part: Part::Both,
+            input,
         };
+        let path = day.path(options);
+
+        let text = match advent::load_input(&path) {
+            Ok(text) => text,
+            Err(e) => {
+                println!("Day {} ({}): {}", number, solver.name(), e);
+                failed = true;
+                continue;
+            }
+        };
+
+        let problems = solver.validate(&day, &text, options);
+        let path = if path == "-" { "<stdin>".to_string() } else { path };
+
+        if problems.is_empty() {
+            println!("Day {} ({}): ok", number, solver.name());
+            continue;
+        }
+
+        failed = true;
+        let plural = if problems.len() == 1 { "" } else { "s" };
+        println!(
+            "Day {} ({}): {} problem{} in {}",
+            number,
+            solver.name(),
+            problems.len(),
+            plural,
+            path
+        );
+        for problem in problems.iter().take(validate::MAX_DIAGNOSTICS) {
+            println!("  {}", validate::describe(&path, &text, problem).replace('\n', "\n  "));
+        }
+        if problems.len() > validate::MAX_DIAGNOSTICS {
+            println!("  ...and {} more", problems.len() - validate::MAX_DIAGNOSTICS);
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn run_all(part: Part, input: Input, options: &Options) {
+    let outcomes: Vec<Outcome> = for_each_day(options.year, options.jobs, |number, solver| {
+        let day = Day { number, part, input };
 
-        let solution = solver.solve(day, check);
-        times.insert(number, solution);
+        Outcome {
+            number,
+            name: solver.name(),
+            result: advent::solve_within(solver, day, options),
+        }
+    });
+
+    match options.format {
+        Format::Table => print_all(&outcomes),
+        Format::Json => print!("{}", output::json(&outcomes)),
+        Format::Csv => print!("{}", output::csv(&outcomes)),
+    }
+
+    if options.jobs > 1 {
+        let note = format!("Note: timings were measured with up to {} days running at once", options.jobs);
+        match options.format {
+            Format::Table => println!("{}", note),
+            _ => eprintln!("{}", note),
+        }
     }
 
-    let total: Duration = times
-        .values()
-        .map(|sol| sol.parse_duration + sol.part_1.as_ref().unwrap().1 + sol.part_2.as_ref().unwrap().1)
+    if let Some(path) = &options.report {
+        write_report(path, input, &outcomes, options);
+    }
+
+    exit_on_failure(&outcomes);
+}
+
+/// Writes the `--report` page, setting the run's timings against the `--compare` baseline's medians if there is one.
+fn write_report(path: &str, input: Input, outcomes: &[Outcome], options: &Options) {
+    let result = (|| {
+        let title = match input {
+            Input::Sample => format!("Advent of Code {}, samples", options.year),
+            Input::Real => format!("Advent of Code {}", options.year),
+        };
+
+        let deltas = match &options.compare {
+            Some(name) => {
+                let saved = baseline::load(&options.baseline_path(name))?;
+                let entries: Vec<Entry> = outcomes
+                    .iter()
+                    .filter_map(|outcome| outcome.result.as_ref().ok())
+                    .filter_map(Entry::measured)
+                    .collect();
+                Some((name, baseline::compare(&saved, &entries)))
+            }
+            None => None,
+        };
+        let comparison = deltas.as_ref().map(|(name, deltas)| Comparison {
+            name,
+            deltas,
+            threshold: options.threshold,
+        });
+
+        report::write(path, &title, outcomes, comparison.as_ref())
+    })();
+
+    match result {
+        Ok(()) => eprintln!("Wrote report to {}", path),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Runs `f` for every day on up to `jobs` threads, handing back the results in day order.
+fn for_each_day<T, F>(year: u16, jobs: usize, f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(usize, &'static dyn Solvifier) -> T + Sync,
+{
+    let days = days(year);
+
+    // keep single-threaded runs on the main thread, which has a much bigger stack than spawned ones
+    if jobs <= 1 {
+        return days.iter().enumerate().map(|(i, solver)| f(i + 1, *solver)).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<(usize, T)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.min(days.len()))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(solver) = days.get(i) else { break };
+                        done.push((i + 1, f(i + 1, *solver)));
+                    }
+                    done
+                })
+            })
+            .collect();
+
+        workers.into_iter().flat_map(|worker| worker.join().unwrap()).collect()
+    });
+
+    results.sort_unstable_by_key(|(number, _)| *number);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+fn print_all(outcomes: &[Outcome]) {
+    let elapsed = |part: &Option<(String, Duration)>| part.as_ref().map(|(_, elapsed)| *elapsed);
+
+    let total: Duration = outcomes
+        .iter()
+        .flat_map(|outcome| &outcome.result)
+        .map(|sol| sol.parse_duration + elapsed(&sol.part_1).unwrap_or_default() + elapsed(&sol.part_2).unwrap_or_default())
         .sum();
 
+    let show_allocs = outcomes.iter().flat_map(|outcome| &outcome.result).any(|sol| sol.allocs.is_some());
+
     let mut table = Table::new();
     table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
-    table.set_titles(row!["Day", "Name", "Parse (µs)", "Part 1 (µs)", "Part 2 (µs)", "Total (µs)"]);
+    let mut titles = row!["Day", "Name", "Parse (µs)", "Part 1 (µs)", "Part 2 (µs)", "Total (µs)"];
+    if show_allocs {
+        for phase in ["Parse", "P1", "P2"] {
+            for stat in ["allocs", "bytes", "peak"] {
+                titles.add_cell(Cell::new(&format!("{} {}", phase, stat)));
+            }
+        }
+    }
+    table.set_titles(titles);
 
-    for (day, sol) in times {
-        let part_1 = sol.part_1.unwrap().1;
-        let part_2 = sol.part_2.unwrap().1;
+    let span = if show_allocs { 13 } else { 4 };
+    for outcome in outcomes {
+        match &outcome.result {
+            Ok(Solution { check: Check::Skipped, .. }) => {
+                let label = Cell::new("skipped").with_hspan(span);
+                table.add_row(Row::new(vec![number_cell(outcome.number), Cell::new(outcome.name), label]));
+            }
+            Ok(sol) if outcome.error().is_none() => {
+                let part_1 = elapsed(&sol.part_1);
+                let part_2 = elapsed(&sol.part_2);
+                let micros = |d: Option<Duration>| d.map_or(String::new(), |d| d.as_micros().to_string());
 
-        table.add_row(row![
-            r -> day,
-            l -> sol.name,
-            r -> sol.parse_duration.as_micros(),
-            r -> part_1.as_micros(),
-            r -> part_2.as_micros(),
-            r -> sol.parse_duration.as_micros() + part_1.as_micros() + part_2.as_micros()
-        ]);
+                let mut row = row![
+                    r -> outcome.number,
+                    l -> sol.name,
+                    r -> sol.parse_duration.as_micros(),
+                    r -> micros(part_1),
+                    r -> micros(part_2),
+                    r -> sol.parse_duration.as_micros() + part_1.unwrap_or_default().as_micros() + part_2.unwrap_or_default().as_micros()
+                ];
+                if let Some(allocs) = &sol.allocs {
+                    for cell in alloc_cells(allocs) {
+                        row.add_cell(cell);
+                    }
+                }
+                table.add_row(row);
+            }
+            result => {
+                // a phase that ran out of time says which, and the ones after it never ran
+                let label = match result {
+                    Ok(sol) => match sol.timed_out_in() {
+                        Some(phase) => format!("TIMEOUT in {}", phase),
+                        None => "FAILED: wrong answer".to_string(),
+                    },
+                    Err(_) => "FAILED".to_string(),
+                };
+                let label = Cell::new(&label).with_hspan(span);
+                table.add_row(Row::new(vec![number_cell(outcome.number), Cell::new(outcome.name), label]));
+            }
+        }
     }
 
     table.printstd();
     println!("Total: {:?}", total);
 }
 
-fn run_one(day: Day, check: bool) {
-    let days = days();
-    let solver = days.get(day.number - 1).expect("Day not found");
+fn number_cell(number: usize) -> Cell {
+    Cell::new(&number.to_string()).style_spec("r")
+}
+
+/// Count, bytes and peak for each phase, with blanks for a part that wasn't run.
+fn alloc_cells(allocs: &PhaseAllocs) -> Vec<Cell> {
+    let mut cells = Vec::new();
+    for phase in [Some(allocs.parse), allocs.part_1, allocs.part_2] {
+        match phase {
+            Some(phase) => {
+                cells.push(Cell::new(&phase.count.to_string()).style_spec("r"));
+                cells.push(Cell::new(&bytes(phase.bytes)).style_spec("r"));
+                cells.push(Cell::new(&bytes(phase.peak)).style_spec("r"));
+            }
+            None => cells.push(Cell::new("").with_hspan(3)),
+        }
+    }
+    cells
+}
+
+fn bytes(n: u64) -> String {
+    match n {
+        0..1024 => format!("{} B", n),
+        1024..1_048_576 => format!("{:.1} KiB", n as f64 / 1024.0),
+        _ => format!("{:.1} MiB", n as f64 / 1_048_576.0),
+    }
+}
+
+fn run_one(day: Day, options: &Options) {
+    let solver = advent_2024::day(options.year, day.number).expect("Day not found");
+
+    let outcome = Outcome {
+        number: day.number,
+        name: solver.name(),
+        result: advent::solve_within(solver, day, options),
+    };
+
+    match options.format {
+        Format::Table => {
+            if let Ok(sol) = &outcome.result {
+                print_one(sol);
+            }
+        }
+        Format::Json => print!("{}", output::json(std::slice::from_ref(&outcome))),
+        Format::Csv => print!("{}", output::csv(std::slice::from_ref(&outcome))),
+    }
+
+    exit_on_failure(std::slice::from_ref(&outcome));
+}
+
+fn print_one(sol: &Solution) {
+    if let Check::Skipped = sol.check {
+        println!("Skipped day {}: the sample is empty", sol.number);
+        return;
+    }
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+
+    let mut add = |mut row: Row, allocs: Option<Allocs>| {
+        if let Some(allocs) = allocs {
+            row.add_cell(Cell::new(&allocs.count.to_string()).style_spec("r"));
+            row.add_cell(Cell::new(&bytes(allocs.bytes)).style_spec("r"));
+            row.add_cell(Cell::new(&bytes(allocs.peak)).style_spec("r"));
+        }
+        table.add_row(row);
+    };
+
+    if sol.timed_out_in() != Some(Phase::Parse) {
+        add(row!["Parse", "", format!("{:?}", sol.parse_duration)], sol.allocs.map(|a| a.parse));
+    }
+
+    if let Some((part_1, elapsed)) = &sol.part_1 {
+        add(row![r -> "1", part_1, format!("{:?}", elapsed)], sol.allocs.and_then(|a| a.part_1));
+    }
+
+    if let Some((part_2, elapsed)) = &sol.part_2 {
+        add(row![r -> "2", part_2, format!("{:?}", elapsed)], sol.allocs.and_then(|a| a.part_2));
+    }
+
+    match sol.timed_out_in() {
+        Some(Phase::Parse) => add(row!["Parse", "TIMEOUT", ""], None),
+        Some(Phase::Part1) => add(row![r -> "1", "TIMEOUT", ""], None),
+        Some(Phase::Part2) => add(row![r -> "2", "TIMEOUT", ""], None),
+        None => {}
+    }
+
+    let mut titles = row!["Part", "Result", "Time"];
+    if sol.allocs.is_some() {
+        for title in ["Allocs", "Bytes", "Peak"] {
+            titles.add_cell(Cell::new(title));
+        }
+    }
+    table.set_titles(titles);
+
+    table.printstd();
+}
+
+fn exit_on_failure(outcomes: &[Outcome]) {
+    let errors: Vec<&Error> = outcomes.iter().filter_map(Outcome::error).collect();
+    if !errors.is_empty() {
+        for e in errors {
+            eprintln!("Error: {}", e);
+        }
+        process::exit(1);
+    }
+}
+
+fn bench_all(part: Part, input: Input, options: &Options, iterations: usize) {
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    table.set_titles(row![
+        "Day",
+        "Name",
+        "Parse min",
+        "Parse med",
+        "Parse p95",
+        "Parse σ",
+        "P1 min",
+        "P1 med",
+        "P1 p95",
+        "P1 σ",
+        "P2 min",
+        "P2 med",
+        "P2 p95",
+        "P2 σ"
+    ]);
+
+    let benches = for_each_day(options.year, options.jobs, |number, solver| {
+        let day = Day { number, part, input };
+        (number, solver.name(), solver.bench(day, options, iterations))
+    });
 
-    let sol = solver.solve(day, check);
+    let mut total = Duration::ZERO;
+    let mut failures = Vec::new();
+    let mut entries = Vec::new();
+    for (number, name, bench) in benches {
+        match bench {
+            Ok(bench) => {
+                entries.push(Entry::new(number, &bench));
+                let part_1 = bench.part_1.map(|(_, stats)| stats);
+                let part_2 = bench.part_2.map(|(_, stats)| stats);
+                total += [Some(&bench.parse), part_1.as_ref(), part_2.as_ref()]
+                    .into_iter()
+                    .flatten()
+                    .map(|stats| stats.median)
+                    .sum::<Duration>();
+
+                let mut cells = vec![Cell::new(&number.to_string()).style_spec("r"), Cell::new(bench.name)];
+                for stats in [Some(&bench.parse), part_1.as_ref(), part_2.as_ref()] {
+                    match stats {
+                        Some(stats) => {
+                            for d in [stats.min, stats.median, stats.p95, stats.stddev] {
+                                cells.push(Cell::new(&d.as_micros().to_string()).style_spec("r"));
+                            }
+                        }
+                        None => cells.push(Cell::new("").with_hspan(4)),
+                    }
+                }
+                table.add_row(Row::new(cells));
+            }
+            Err(e) => {
+                table.add_row(row![r -> number, l -> name, H12 -> "FAILED"]);
+                failures.push(e);
+            }
+        }
+    }
+
+    table.printstd();
+    println!("All times in µs over {} iterations", iterations);
+    println!("Total of medians: {:?}", total);
+    if options.jobs > 1 {
+        println!("Note: timings were measured with up to {} days running at once", options.jobs);
+    }
+
+    let regressed = track_baseline(&entries, options);
+
+    if !failures.is_empty() {
+        for e in failures {
+            eprintln!("Error: {}", e);
+        }
+        process::exit(1);
+    }
+
+    if regressed {
+        process::exit(1);
+    }
+}
+
+fn bench_one(day: Day, options: &Options, iterations: usize) {
+    let number = day.number;
+    let solver = advent_2024::day(options.year, number).expect("Day not found");
+
+    let bench = match solver.bench(day, options, iterations) {
+        Ok(bench) => bench,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    };
 
     let mut table = Table::new();
     table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    table.set_titles(row!["Part", "Result", "Min", "Median", "p95", "Stddev"]);
 
-    table.add_row(row!["Parse", "", format!("{:?}", sol.parse_duration)]);
-    table.set_titles(row!["Part", "Result", "Time"]);
+    let mut add = |label: &str, answer: &str, stats: &Stats| {
+        table.add_row(row![
+            r -> label,
+            answer,
+            format!("{:?}", stats.min),
+            format!("{:?}", stats.median),
+            format!("{:?}", stats.p95),
+            format!("{:?}", stats.stddev)
+        ]);
+    };
 
-    if let Some((part_1, elapsed)) = sol.part_1 {
-        table.add_row(row![r -> "1", part_1, format!("{:?}", elapsed)]);
+    add("Parse", "", &bench.parse);
+
+    if let Some((part_1, stats)) = &bench.part_1 {
+        add("1", part_1, stats);
     }
 
-    if let Some((part_2, elapsed)) = sol.part_2 {
-        table.add_row(row![r -> "2", part_2, format!("{:?}", elapsed)]);
+    if let Some((part_2, stats)) = &bench.part_2 {
+        add("2", part_2, stats);
     }
 
     table.printstd();
+    println!("{} iterations", bench.iterations);
+
+    if track_baseline(&[Entry::new(number, &bench)], options) {
+        process::exit(1);
+    }
 }
 
-fn days() -> Vec<&'static dyn Solvifier> {
-    vec![
-        &day01::Day01,
-        &day02::Day02,
-        &day03::Day03,
-        &day04::Day04,
-        &day05::Day05,
-        &day06::Day06,
-        &day07::Day07,
-        &day08::Day08,
-        &day09::Day09,
-        &day10::Day10,
-        &day11::Day11,
-        &day12::Day12,
-        &day13::Day13,
-        &day14::Day14,
-        &day15::Day15,
-        &day16::Day16,
-        &day17::Day17,
-        &day18::Day18,
-        &day19::Day19,
-        &day20::Day20,
-        &day21::Day21,
-        &day22::Day22,
-        &day23::Day23,
-        &day24::Day24,
-        &day25::Day25,
-    ]
+/// Saves the medians and/or compares them with a saved baseline, returning whether anything got slower than the
+/// threshold allows.
+fn track_baseline(entries: &[Entry], options: &Options) -> bool {
+    let result = (|| {
+        let mut regressed = false;
+
+        if let Some(name) = &options.compare {
+            let saved = baseline::load(&options.baseline_path(name))?;
+            let deltas = baseline::compare(&saved, entries);
+            print_comparison(name, &deltas, options.threshold);
+            regressed = deltas.iter().any(|delta| delta.is_regression(options.threshold));
+        }
+
+        if let Some(name) = &options.save_baseline {
+            let path = options.baseline_path(name);
+            baseline::save(&path, entries)?;
+            println!("Saved baseline {:?} to {}", name, path);
+        }
+
+        Ok::<_, Error>(regressed)
+    })();
+
+    result.unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    })
+}
+
+fn print_comparison(name: &str, deltas: &[Delta], threshold: f64) {
+    if deltas.is_empty() {
+        println!("Nothing in baseline {:?} to compare with", name);
+        return;
+    }
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    table.set_titles(row!["Day", "Phase", "Baseline (µs)", "Now (µs)", "Change"]);
+
+    for delta in deltas {
+        let mut change = format!("{:+.1}%", delta.change());
+        if delta.is_regression(threshold) {
+            change.push_str(" SLOWER");
+        }
+
+        table.add_row(row![
+            r -> delta.day,
+            l -> delta.phase,
+            r -> delta.before.as_micros(),
+            r -> delta.after.as_micros(),
+            r -> change
+        ]);
+    }
+
+    let before: Duration = deltas.iter().map(|delta| delta.before).sum();
+    let after: Duration = deltas.iter().map(|delta| delta.after).sum();
+
+    println!(
+        "Compared with baseline {:?}, failing anything more than {}% slower:",
+        name, threshold
+    );
+    table.printstd();
+    println!("Total of medians: {:?} before, {:?} now", before, after);
 }
@@ -0,0 +1,124 @@
+use crate::advent::{Check, Outcome};
+use std::fmt::Write;
+use std::time::Duration;
+
+pub fn json(outcomes: &[Outcome]) -> String {
+    let mut out = String::from("[\n");
+
+    for (i, outcome) in outcomes.iter().enumerate() {
+        let mut fields = vec![
+            format!("\"day\": {}", outcome.number),
+            format!("\"name\": {}", json_string(outcome.name)),
+        ];
+
+        match &outcome.result {
+            Ok(sol) => {
+                fields.push(format!("\"parse_ns\": {}", sol.parse_duration.as_nanos()));
+                fields.push(format!("\"part_1\": {}", json_part(&sol.part_1)));
+                fields.push(format!("\"part_2\": {}", json_part(&sol.part_2)));
+            }
+            Err(_) => {
+                fields.push("\"parse_ns\": null".to_string());
+                fields.push("\"part_1\": null".to_string());
+                fields.push("\"part_2\": null".to_string());
+            }
+        }
+
+        fields.push(format!("\"check\": {}", json_string(check_status(outcome))));
+        fields.push(format!(
+            "\"error\": {}",
+            outcome.error().map_or("null".to_string(), |e| json_string(&e.to_string()))
+        ));
+
+        let comma = if i + 1 < outcomes.len() { "," } else { "" };
+        writeln!(out, "  {{{}}}{}", fields.join(", "), comma).unwrap();
+    }
+
+    out.push_str("]\n");
+    out
+}
+
+pub fn csv(outcomes: &[Outcome]) -> String {
+    let mut out = String::from("day,name,parse_ns,part_1,part_1_ns,part_2,part_2_ns,check,error\n");
+
+    for outcome in outcomes {
+        let (parse, part_1, part_2) = match &outcome.result {
+            Ok(sol) => (
+                sol.parse_duration.as_nanos().to_string(),
+                csv_part(&sol.part_1),
+                csv_part(&sol.part_2),
+            ),
+            Err(_) => (String::new(), csv_part(&None), csv_part(&None)),
+        };
+
+        let error = outcome.error().map(|e| e.to_string()).unwrap_or_default();
+
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{}",
+            outcome.number,
+            csv_field(outcome.name),
+            parse,
+            part_1,
+            part_2,
+            check_status(outcome),
+            csv_field(&error)
+        )
+        .unwrap();
+    }
+
+    out
+}
+
+pub(crate) fn check_status(outcome: &Outcome) -> &'static str {
+    match &outcome.result {
+        Ok(sol) if sol.timed_out.is_some() => "timeout",
+        Ok(sol) => match sol.check {
+            Check::Unchecked => "unchecked",
+            Check::Passed => "passed",
+            Check::Failed(_) => "failed",
+            Check::Skipped => "skipped",
+        },
+        Err(_) => "error",
+    }
+}
+
+fn json_part(part: &Option<(String, Duration)>) -> String {
+    match part {
+        Some((answer, elapsed)) => format!("{{\"answer\": {}, \"ns\": {}}}", json_string(answer), elapsed.as_nanos()),
+        None => "null".to_string(),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv_part(part: &Option<(String, Duration)>) -> String {
+    match part {
+        Some((answer, elapsed)) => format!("{},{}", csv_field(answer), elapsed.as_nanos()),
+        None => ",".to_string(),
+    }
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
@@ -0,0 +1,109 @@
+//! Snapshots of a puzzle's state for `--render`: each frame is written both as text and as a PPM image, numbered in
+//! the order they were taken so a directory of them can be flipped through or stitched into an animation.
+
+use crate::advent::{Error, Result};
+use std::cell::Cell;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::PathBuf;
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+pub const GREY: Rgb = [96, 96, 96];
+pub const RED: Rgb = [220, 50, 47];
+pub const GREEN: Rgb = [64, 192, 64];
+pub const BLUE: Rgb = [38, 139, 210];
+pub const YELLOW: Rgb = [230, 190, 40];
+
+/// Each part's frames stop after this many, since a solver that renders every step of a real input would otherwise fill
+/// the disk.
+pub const MAX_FRAMES: usize = 500;
+
+/// Images are scaled up until their longer side is at least this many pixels, so the samples aren't a few specks.
+const MIN_IMAGE_SIDE: usize = 256;
+
+/// Something laid out on a grid of cells, each shown as a character in the text frame and a colour in the image.
+pub trait Render {
+    /// Width and height, in cells.
+    fn size(&self) -> (usize, usize);
+    fn cell(&self, x: usize, y: usize) -> (char, Rgb);
+
+    fn ascii(&self) -> String {
+        let (width, height) = self.size();
+        let mut out = String::with_capacity((width + 1) * height);
+        for y in 0..height {
+            for x in 0..width {
+                out.push(self.cell(x, y).0);
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// A binary PPM (P6), which needs no encoder and which most image tools read.
+    fn ppm(&self) -> Vec<u8> {
+        let (width, height) = self.size();
+        let scale = (MIN_IMAGE_SIDE / width.max(height).max(1)).max(1);
+
+        let mut header = String::new();
+        write!(header, "P6\n{} {}\n255\n", width * scale, height * scale).unwrap();
+
+        let mut out = header.into_bytes();
+        out.reserve(width * height * scale * scale * 3);
+        for y in 0..height {
+            let row: Vec<Rgb> = (0..width).map(|x| self.cell(x, y).1).collect();
+            for _ in 0..scale {
+                for rgb in &row {
+                    for _ in 0..scale {
+                        out.extend_from_slice(rgb);
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Where one part of one day writes its frames.
+pub(crate) struct Frames {
+    day: usize,
+    dir: PathBuf,
+    count: Cell<usize>,
+}
+
+impl Frames {
+    pub(crate) fn new(day: usize, dir: impl Into<PathBuf>) -> Self {
+        Frames {
+            day,
+            dir: dir.into(),
+            count: Cell::new(0),
+        }
+    }
+
+    pub(crate) fn write(&self, label: &str, frame: &dyn Render) -> Result<()> {
+        let count = self.count.get() + 1;
+        self.count.set(count);
+
+        if count > MAX_FRAMES {
+            if count == MAX_FRAMES + 1 {
+                eprintln!("[day {}] stopped rendering after {} frames", self.day, MAX_FRAMES);
+            }
+            return Ok(());
+        }
+
+        let io = |e: std::io::Error| Error::io(format!("{}: {}", self.dir.display(), e));
+        if count == 1 {
+            // the directory is this part's alone, and frames left over from a longer run would muddle this one's
+            if self.dir.exists() {
+                fs::remove_dir_all(&self.dir).map_err(io)?;
+            }
+            fs::create_dir_all(&self.dir).map_err(io)?;
+        }
+
+        let stem = self.dir.join(format!("{:05}-{}", count, label));
+        fs::write(stem.with_extension("txt"), frame.ascii()).map_err(io)?;
+        fs::write(stem.with_extension("ppm"), frame.ppm()).map_err(io)
+    }
+}
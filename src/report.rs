@@ -0,0 +1,351 @@
+//! `--report`, a page of a full run for pasting into the weekly review: every day's answers and check status, a table
+//! and bar chart of the timings, and how they moved since a `--compare` baseline. Everything it needs is inline, so
+//! the one file can go anywhere.
+
+use crate::advent::{Error, Outcome, Phase, Result};
+use crate::baseline::Delta;
+use crate::output;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// Parse, part 1 and part 2, in the order their bars are stacked.
+const PHASE_COLOURS: [(&str, &str); 3] = [("Parse", "#8da0cb"), ("Part 1", "#66c2a5"), ("Part 2", "#fc8d62")];
+
+const LABEL_WIDTH: usize = 200;
+const BAR_WIDTH: usize = 560;
+const ROW_HEIGHT: usize = 20;
+
+/// A baseline to show the run's timings against.
+pub struct Comparison<'a> {
+    pub name: &'a str,
+    pub deltas: &'a [Delta],
+    pub threshold: f64,
+}
+
+/// Writes the page for `outcomes` to `path`.
+pub fn write(path: &str, title: &str, outcomes: &[Outcome], comparison: Option<&Comparison>) -> Result<()> {
+    if let Some(dir) = Path::new(path).parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir).map_err(|e| Error::io(format!("{}: {}", dir.display(), e)))?;
+    }
+    fs::write(path, html(title, outcomes, comparison)).map_err(|e| Error::io(format!("{}: {}", path, e)))
+}
+
+pub fn html(title: &str, outcomes: &[Outcome], comparison: Option<&Comparison>) -> String {
+    let mut out = String::new();
+    writeln!(out, "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">").unwrap();
+    writeln!(out, "<title>{}</title>\n<style>{}</style>\n</head>\n<body>", escape(title), STYLE).unwrap();
+    writeln!(out, "<h1>{}</h1>", escape(title)).unwrap();
+
+    let total: Duration = outcomes
+        .iter()
+        .map(|outcome| phases(outcome).into_iter().flatten().sum::<Duration>())
+        .sum();
+    let failed = outcomes.iter().filter(|outcome| outcome.error().is_some()).count();
+    writeln!(
+        out,
+        "<p>{} days in {} µs, {} failed.</p>",
+        outcomes.len(),
+        micros(Some(total)),
+        failed
+    )
+    .unwrap();
+
+    out.push_str("<h2>Answers</h2>\n");
+    out.push_str(&table(outcomes, comparison));
+
+    out.push_str("<h2>Timings</h2>\n");
+    out.push_str(&timings_chart(outcomes));
+
+    if let Some(comparison) = comparison {
+        writeln!(
+            out,
+            "<h2>Against baseline {}</h2>\n<p>Change in each day's total, marked when any phase got more than {}% \
+             slower.</p>",
+            escape(comparison.name),
+            comparison.threshold
+        )
+        .unwrap();
+        out.push_str(&deltas_chart(comparison));
+    }
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn table(outcomes: &[Outcome], comparison: Option<&Comparison>) -> String {
+    let mut out = String::from("<table>\n<tr><th>Day</th><th>Name</th><th>Part 1</th><th>Part 2</th><th>Check</th>");
+    out.push_str("<th>Parse (µs)</th><th>Part 1 (µs)</th><th>Part 2 (µs)</th><th>Total (µs)</th>");
+    if comparison.is_some() {
+        out.push_str("<th>Parse Δ</th><th>Part 1 Δ</th><th>Part 2 Δ</th>");
+    }
+    out.push_str("</tr>\n");
+
+    for outcome in outcomes {
+        let answer = |part: Option<&(String, Duration)>| part.map_or(String::new(), |(answer, _)| escape(answer));
+        let (part_1, part_2) = match &outcome.result {
+            Ok(sol) => (answer(sol.part_1.as_ref()), answer(sol.part_2.as_ref())),
+            Err(_) => (String::new(), String::new()),
+        };
+
+        let status = output::check_status(outcome);
+        let error = outcome
+            .error()
+            .map_or(String::new(), |e| format!(" title=\"{}\"", escape(&e.to_string())));
+
+        let times = phases(outcome);
+        let total = times.iter().flatten().sum::<Duration>();
+        write!(
+            out,
+            "<tr><td class=\"num\">{}</td><td>{}</td><td class=\"answer\">{}</td><td class=\"answer\">{}</td>\
+             <td class=\"{}\"{}>{}</td>",
+            outcome.number,
+            escape(outcome.name),
+            part_1,
+            part_2,
+            status,
+            error,
+            status
+        )
+        .unwrap();
+        for time in times {
+            write!(out, "<td class=\"num\">{}</td>", micros(time)).unwrap();
+        }
+        write!(out, "<td class=\"num\">{}</td>", micros(Some(total))).unwrap();
+
+        if let Some(comparison) = comparison {
+            for phase in ["parse", "part 1", "part 2"] {
+                let delta = comparison
+                    .deltas
+                    .iter()
+                    .find(|delta| delta.day == outcome.number && delta.phase == phase);
+                match delta {
+                    Some(delta) => {
+                        let class = if delta.is_regression(comparison.threshold) {
+                            "num slower"
+                        } else {
+                            "num"
+                        };
+                        write!(out, "<td class=\"{}\">{:+.1}%</td>", class, delta.change()).unwrap();
+                    }
+                    None => out.push_str("<td></td>"),
+                }
+            }
+        }
+        out.push_str("</tr>\n");
+    }
+
+    out.push_str("</table>\n");
+    out
+}
+
+/// A bar per day, with its phases stacked and scaled against the slowest day.
+fn timings_chart(outcomes: &[Outcome]) -> String {
+    let longest = outcomes
+        .iter()
+        .map(|outcome| phases(outcome).into_iter().flatten().sum::<Duration>())
+        .max()
+        .unwrap_or_default()
+        .max(Duration::from_nanos(1));
+    let scale = |d: Duration| d.as_secs_f64() / longest.as_secs_f64() * BAR_WIDTH as f64;
+
+    let legend = ROW_HEIGHT;
+    let height = legend + outcomes.len() * ROW_HEIGHT + ROW_HEIGHT / 2;
+    let mut out = svg_open(height);
+
+    let mut x = LABEL_WIDTH;
+    for (name, colour) in PHASE_COLOURS {
+        write!(
+            out,
+            "<rect x=\"{}\" y=\"2\" width=\"12\" height=\"12\" fill=\"{}\"/><text x=\"{}\" y=\"13\">{}</text>",
+            x,
+            colour,
+            x + 16,
+            name
+        )
+        .unwrap();
+        x += 80;
+    }
+    out.push('\n');
+
+    for (i, outcome) in outcomes.iter().enumerate() {
+        let y = legend + i * ROW_HEIGHT;
+        out.push_str(&row_label(outcome, y));
+
+        let mut x = LABEL_WIDTH as f64;
+        let times = phases(outcome);
+        for ((name, colour), time) in PHASE_COLOURS.iter().zip(times) {
+            let Some(time) = time else { continue };
+            let width = scale(time);
+            write!(
+                out,
+                "<rect x=\"{:.1}\" y=\"{}\" width=\"{:.1}\" height=\"{}\" fill=\"{}\"><title>{}: {} µs</title></rect>",
+                x,
+                y + 3,
+                width,
+                ROW_HEIGHT - 6,
+                colour,
+                name,
+                micros(Some(time))
+            )
+            .unwrap();
+            x += width;
+        }
+
+        let label = match &outcome.result {
+            Ok(_) => format!("{} µs", micros(Some(times.iter().flatten().sum()))),
+            Err(_) => "failed".to_string(),
+        };
+        writeln!(out, "<text x=\"{:.1}\" y=\"{}\">{}</text>", x + 4.0, y + 14, label).unwrap();
+    }
+
+    out.push_str("</svg>\n");
+    out
+}
+
+/// A bar per day for how much its total moved, slower to the right of the middle and faster to the left, scaled
+/// against the biggest move.
+fn deltas_chart(comparison: &Comparison) -> String {
+    let mut days: Vec<usize> = comparison.deltas.iter().map(|delta| delta.day).collect();
+    days.dedup();
+
+    let changes: Vec<(usize, f64, bool)> = days
+        .into_iter()
+        .map(|day| {
+            let mut deltas = comparison.deltas.iter().filter(|delta| delta.day == day);
+            let before: Duration = deltas.clone().map(|delta| delta.before).sum();
+            let after: Duration = deltas.clone().map(|delta| delta.after).sum();
+            let change = if before.is_zero() {
+                0.0
+            } else {
+                (after.as_secs_f64() - before.as_secs_f64()) / before.as_secs_f64() * 100.0
+            };
+            let regressed = deltas.any(|delta| delta.is_regression(comparison.threshold));
+            (day, change, regressed)
+        })
+        .collect();
+
+    let biggest = changes.iter().map(|(_, change, _)| change.abs()).fold(1.0, f64::max);
+    let half = BAR_WIDTH as f64 / 2.0 - 60.0;
+    let middle = LABEL_WIDTH as f64 + BAR_WIDTH as f64 / 2.0;
+
+    let mut out = svg_open(changes.len() * ROW_HEIGHT + ROW_HEIGHT / 2);
+    writeln!(
+        out,
+        "<line x1=\"{:.1}\" y1=\"0\" x2=\"{:.1}\" y2=\"{}\" stroke=\"#999\"/>",
+        middle,
+        middle,
+        changes.len() * ROW_HEIGHT
+    )
+    .unwrap();
+
+    for (i, (day, change, regressed)) in changes.iter().enumerate() {
+        let y = i * ROW_HEIGHT;
+        write!(
+            out,
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"end\">Day {}</text>",
+            LABEL_WIDTH - 8,
+            y + 14,
+            day
+        )
+        .unwrap();
+
+        let width = change.abs() / biggest * half;
+        let x = if *change < 0.0 { middle - width } else { middle };
+        let colour = match (regressed, *change < 0.0) {
+            (true, _) => "#d62728",
+            (false, true) => "#66c2a5",
+            (false, false) => "#bbbbbb",
+        };
+        write!(
+            out,
+            "<rect x=\"{:.1}\" y=\"{}\" width=\"{:.1}\" height=\"{}\" fill=\"{}\"/>",
+            x,
+            y + 3,
+            width,
+            ROW_HEIGHT - 6,
+            colour
+        )
+        .unwrap();
+
+        let (text_x, anchor) = if *change < 0.0 {
+            (x - 4.0, "end")
+        } else {
+            (x + width + 4.0, "start")
+        };
+        writeln!(
+            out,
+            "<text x=\"{:.1}\" y=\"{}\" text-anchor=\"{}\">{:+.1}%</text>",
+            text_x,
+            y + 14,
+            anchor,
+            change
+        )
+        .unwrap();
+    }
+
+    out.push_str("</svg>\n");
+    out
+}
+
+fn svg_open(height: usize) -> String {
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" font-size=\"12\">\n",
+        LABEL_WIDTH + BAR_WIDTH + 100,
+        height
+    )
+}
+
+fn row_label(outcome: &Outcome, y: usize) -> String {
+    format!(
+        "<text x=\"{}\" y=\"{}\" text-anchor=\"end\">{}. {}</text>",
+        LABEL_WIDTH - 8,
+        y + 14,
+        outcome.number,
+        escape(outcome.name)
+    )
+}
+
+/// How long parse, part 1 and part 2 took, with `None` for whatever didn't run.
+fn phases(outcome: &Outcome) -> [Option<Duration>; 3] {
+    match &outcome.result {
+        Ok(sol) => {
+            let parse = (sol.timed_out_in() != Some(Phase::Parse)).then_some(sol.parse_duration);
+            let elapsed = |part: &Option<(String, Duration)>| part.as_ref().map(|(_, elapsed)| *elapsed);
+            [parse, elapsed(&sol.part_1), elapsed(&sol.part_2)]
+        }
+        Err(_) => [None; 3],
+    }
+}
+
+fn micros(d: Option<Duration>) -> String {
+    d.map_or(String::new(), |d| d.as_micros().to_string())
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+const STYLE: &str = "\
+body { font-family: system-ui, sans-serif; margin: 2em; color: #222; }
+table { border-collapse: collapse; }
+th, td { padding: 0.25em 0.75em; border-bottom: 1px solid #ddd; text-align: left; }
+td.num { text-align: right; font-variant-numeric: tabular-nums; }
+td.answer { font-family: monospace; }
+td.passed { color: #1a7f37; }
+td.failed, td.error, td.timeout, td.slower { color: #d62728; font-weight: bold; }
+td.unchecked, td.skipped { color: #888; }
+svg text { fill: #222; }
+";
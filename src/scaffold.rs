@@ -0,0 +1,116 @@
+//! `new-day`, which starts a day from `templates/day.rs`. There's nothing to register by hand, because build.rs picks up
+//! every `src/yearYYYY/dayNN.rs` on the next build. The module does need declaring in `src/yearYYYY/mod.rs` (and a new
+//! year in `src/lib.rs`), and the day's fuzz target listing in `fuzz/Cargo.toml`, so those get done here too.
+
+use crate::advent::{Day, Error, Input, Options, Part, Result};
+use std::fs;
+use std::path::Path;
+
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+const FUZZ_TEMPLATE: &str = include_str!("../templates/fuzz_target.rs");
+
+/// Creates the module and empty sample and input files for a day, returning the paths it created.
+pub fn new_day(number: usize, name: &str, options: &Options) -> Result<Vec<String>> {
+    let next = crate::days(options.year).len() + 1;
+    if number != next {
+        return Err(Error::io(format!(
+            "can only add day {} to {} next, the registry is numbered without gaps",
+            next, options.year
+        )));
+    }
+
+    let module = format!("{}/src/year{}/day{:02}.rs", env!("CARGO_MANIFEST_DIR"), options.year, number);
+    if Path::new(&module).exists() {
+        return Err(Error::io(format!("{} already exists", module)));
+    }
+
+    let source = TEMPLATE
+        .replace("__NN__", &format!("{:02}", number))
+        .replace("__NAME__", &format!("{:?}", name));
+    write(&module, &source)?;
+    let mut created = vec![module];
+    created.extend(declare_module(options.year, number)?);
+
+    for input in [Input::Sample, Input::Real] {
+        let day = Day {
+            number,
+            input,
+            part: Part::Both,
+        };
+        let path = day.path(options);
+        if !Path::new(&path).exists() {
+            write(&path, "")?;
+            created.push(path);
+        }
+    }
+
+    created.extend(new_fuzz_target(options.year, number)?);
+
+    Ok(created)
+}
+
+/// Adds `pub mod dayNN;` to the year's `mod.rs`, starting the year if it's new. Returns the paths it created.
+fn declare_module(year: u16, number: usize) -> Result<Vec<String>> {
+    let src = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+    let mod_rs = format!("{}/year{}/mod.rs", src, year);
+    let mut created = Vec::new();
+
+    let mut contents = if Path::new(&mod_rs).exists() {
+        read(&mod_rs)?
+    } else {
+        let lib_rs = format!("{}/lib.rs", src);
+        let lib = read(&lib_rs)?;
+        let Some(last) = lib.lines().rfind(|line| line.starts_with("pub mod year")) else {
+            return Err(Error::io(format!("{} doesn't declare any years to add {} after", lib_rs, year)));
+        };
+        let lib = lib.replacen(last, &format!("{}\npub mod year{};", last, year), 1);
+        write(&lib_rs, &lib)?;
+
+        created.push(mod_rs.clone());
+        format!(
+            "//! The {} puzzles, a module per day. `new-day` adds to the list, and build.rs registers whatever's in it.\n\n",
+            year
+        )
+    };
+
+    contents.push_str(&format!("pub mod day{:02};\n", number));
+    write(&mod_rs, &contents)?;
+
+    Ok(created)
+}
+
+/// Adds the day's target to `fuzz/`, returning its path if there wasn't one already.
+fn new_fuzz_target(year: u16, number: usize) -> Result<Option<String>> {
+    let name = crate::fuzz::target_name(year, number);
+    let dir = format!("{}/fuzz", env!("CARGO_MANIFEST_DIR"));
+    let target = format!("{}/fuzz_targets/{}.rs", dir, name);
+    if Path::new(&target).exists() {
+        return Ok(None);
+    }
+
+    let source = FUZZ_TEMPLATE
+        .replace("__YEAR__", &year.to_string())
+        .replace("__N__", &number.to_string());
+    write(&target, &source)?;
+
+    let manifest = format!("{}/Cargo.toml", dir);
+    let mut contents = read(&manifest)?;
+    contents.push_str(&format!(
+        "\n[[bin]]\nname = \"{}\"\npath = \"fuzz_targets/{}.rs\"\ntest = false\ndoc = false\nbench = false\n",
+        name, name
+    ));
+    write(&manifest, &contents)?;
+
+    Ok(Some(target))
+}
+
+fn read(path: &str) -> Result<String> {
+    fs::read_to_string(path).map_err(|e| Error::io(format!("{}: {}", path, e)))
+}
+
+fn write(path: &str, contents: &str) -> Result<()> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir).map_err(|e| Error::io(format!("{}: {}", dir.display(), e)))?;
+    }
+    fs::write(path, contents).map_err(|e| Error::io(format!("{}: {}", path, e)))
+}
@@ -0,0 +1,364 @@
+//! Shortest-path searches over any state that can be copied and hashed. `neighbors` gives the states one move away
+//! from a state (with what the move costs, for the weighted searches), and `goal` says when to stop.
+
+use hashbrown::hash_map::Entry;
+use hashbrown::{HashMap, HashSet};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+
+/// The cheapest way found to a goal.
+pub struct Path<S> {
+    pub end: S,
+    pub cost: u64,
+    /// Every state reached, with its cost and the state it was reached from.
+    reached: HashMap<S, (u64, Option<S>)>,
+}
+
+impl<S: Copy + Eq + Hash> Path<S> {
+    /// The states along the path, from the start to `end`.
+    pub fn states(&self) -> Vec<S> {
+        let mut states = vec![self.end];
+        while let Some((_, Some(prev))) = self.reached.get(states.last().unwrap()) {
+            states.push(*prev);
+        }
+        states.reverse();
+        states
+    }
+
+    /// How many states the search got to, whether or not they're on the path.
+    pub fn explored(&self) -> usize {
+        self.reached.len()
+    }
+}
+
+/// Breadth-first search, where every move costs 1.
+pub fn bfs<S, I>(start: S, mut neighbors: impl FnMut(&S) -> I, mut goal: impl FnMut(&S) -> bool) -> Option<Path<S>>
+where
+    S: Copy + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut reached = HashMap::from([(start, (0, None))]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, cost)) = queue.pop_front() {
+        if goal(&state) {
+            return Some(Path { end: state, cost, reached });
+        }
+
+        for next in neighbors(&state) {
+            if let Entry::Vacant(e) = reached.entry(next) {
+                e.insert((cost + 1, Some(state)));
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Breadth-first search over states numbered `0..states`, like a grid's indices, keeping track of what's been reached in
+/// a flat list rather than a map. That's most of the work when the moves are cheap to find, and this only gives the cost.
+pub fn bfs_dense<I>(start: usize, states: usize, mut neighbors: impl FnMut(usize) -> I, mut goal: impl FnMut(usize) -> bool) -> Option<u64>
+where
+    I: IntoIterator<Item = usize>,
+{
+    let mut reached = vec![false; states];
+    reached[start] = true;
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, cost)) = queue.pop_front() {
+        if goal(state) {
+            return Some(cost);
+        }
+
+        for next in neighbors(state) {
+            if !reached[next] {
+                reached[next] = true;
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    None
+}
+
+pub fn dijkstra<S, I>(start: S, neighbors: impl FnMut(&S) -> I, goal: impl FnMut(&S) -> bool) -> Option<Path<S>>
+where
+    S: Copy + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(start, neighbors, |_| 0, goal)
+}
+
+/// Dijkstra guided by `heuristic`, which must never overestimate the cost left to a goal, nor drop by more than the
+/// cost of a move, or the path found might not be the cheapest.
+pub fn astar<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Copy + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut reached = HashMap::from([(start, (0, None))]);
+    let mut queue = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        cost: 0,
+        state: start,
+    }]);
+
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        // a leftover from before a cheaper way here turned up
+        if cost > reached[&state].0 {
+            continue;
+        }
+
+        if goal(&state) {
+            return Some(Path { end: state, cost, reached });
+        }
+
+        for (next, step) in neighbors(&state) {
+            let cost = cost + step;
+            match reached.entry(next) {
+                Entry::Occupied(e) if e.get().0 <= cost => continue,
+                Entry::Occupied(mut e) => {
+                    e.insert((cost, Some(state)));
+                }
+                Entry::Vacant(e) => {
+                    e.insert((cost, Some(state)));
+                }
+            }
+            queue.push(Queued {
+                priority: cost + heuristic(&next),
+                cost,
+                state: next,
+            });
+        }
+    }
+
+    None
+}
+
+/// Every cheapest way to a goal, when there's more than one.
+pub struct AllPaths<S> {
+    pub cost: u64,
+    /// The goal states reached at that cost.
+    pub ends: Vec<S>,
+    /// Every state reached, with its cost and all the states it was reached from at that cost.
+    reached: HashMap<S, (u64, Vec<S>)>,
+}
+
+impl<S: Copy + Eq + Hash> AllPaths<S> {
+    /// Every state that's on at least one of the paths.
+    pub fn states(&self) -> HashSet<S> {
+        let mut on_path = HashSet::new();
+        let mut stack = self.ends.clone();
+        while let Some(state) = stack.pop() {
+            if on_path.insert(state) {
+                stack.extend(&self.reached[&state].1);
+            }
+        }
+        on_path
+    }
+}
+
+/// Dijkstra that keeps going until it's found every goal state it can reach at the lowest cost, remembering all the
+/// predecessors of a state that tie for cheapest rather than just the first.
+pub fn dijkstra_all<S, I>(start: S, mut neighbors: impl FnMut(&S) -> I, mut goal: impl FnMut(&S) -> bool) -> Option<AllPaths<S>>
+where
+    S: Copy + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut reached = HashMap::from([(start, (0, Vec::new()))]);
+    let mut queue = BinaryHeap::from([Queued {
+        priority: 0,
+        cost: 0,
+        state: start,
+    }]);
+    let mut found: Option<(u64, Vec<S>)> = None;
+
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        if cost > reached[&state].0 {
+            continue;
+        }
+
+        if let Some((found_cost, ends)) = &mut found {
+            if cost > *found_cost {
+                break;
+            }
+            if goal(&state) {
+                ends.push(state);
+            }
+            continue;
+        } else if goal(&state) {
+            found = Some((cost, vec![state]));
+            continue;
+        }
+
+        for (next, step) in neighbors(&state) {
+            let cost = cost + step;
+            match reached.entry(next) {
+                Entry::Occupied(mut e) => match cost.cmp(&e.get().0) {
+                    Ordering::Greater => continue,
+                    Ordering::Equal => {
+                        e.get_mut().1.push(state);
+                        continue;
+                    }
+                    Ordering::Less => {
+                        e.insert((cost, vec![state]));
+                    }
+                },
+                Entry::Vacant(e) => {
+                    e.insert((cost, vec![state]));
+                }
+            }
+            queue.push(Queued {
+                priority: cost,
+                cost,
+                state: next,
+            });
+        }
+    }
+
+    found.map(|(cost, ends)| AllPaths { cost, ends, reached })
+}
+
+/// A state waiting in a search's queue, ordered so that `BinaryHeap`, which pops the largest, pops the lowest priority.
+struct Queued<S> {
+    priority: u64,
+    cost: u64,
+    state: S,
+}
+
+impl<S> PartialEq for Queued<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S> Eq for Queued<S> {}
+
+impl<S> PartialOrd for Queued<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for Queued<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 5x5 maze with a wall down the middle, open only at the bottom.
+    ///
+    /// ```text
+    /// S.#..
+    /// ..#..
+    /// ..#..
+    /// ..#.G
+    /// .....
+    /// ```
+    fn maze_neighbors((x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+        [(0, -1), (0, 1), (-1, 0), (1, 0)]
+            .into_iter()
+            .map(|(dx, dy)| (x + dx, y + dy))
+            .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y) && !(x == 2 && y < 4))
+            .collect()
+    }
+
+    const GOAL: (i32, i32) = (4, 3);
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs((0, 0), maze_neighbors, |&s| s == GOAL).unwrap();
+        assert_eq!(path.cost, 9);
+        let states = path.states();
+        assert_eq!(states.len(), 10);
+        assert_eq!((states[0], states[9]), ((0, 0), GOAL));
+
+        assert!(bfs((0, 0), maze_neighbors, |&s| s == (2, 0)).is_none());
+    }
+
+    #[test]
+    fn test_bfs_dense() {
+        let neighbors = |i: usize| {
+            maze_neighbors(&((i % 5) as i32, (i / 5) as i32))
+                .into_iter()
+                .map(|(x, y)| (y * 5 + x) as usize)
+        };
+        assert_eq!(bfs_dense(0, 25, neighbors, |i| i == 19), Some(9));
+        assert_eq!(bfs_dense(0, 25, neighbors, |i| i == 2), None);
+        assert_eq!(bfs_dense(7, 25, neighbors, |i| i == 7), Some(0));
+    }
+
+    /// Moving right costs 1 and moving down costs 10, on a 4x4 grid.
+    fn weighted_neighbors(&(x, y): &(u64, u64)) -> Vec<((u64, u64), u64)> {
+        let mut next = Vec::new();
+        if x < 3 {
+            next.push(((x + 1, y), 1));
+        }
+        if y < 3 {
+            next.push(((x, y + 1), 10));
+        }
+        next
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra((0, 0), weighted_neighbors, |&s| s == (3, 3)).unwrap();
+        assert_eq!(path.cost, 33);
+        assert_eq!(path.states().len(), 7);
+
+        // with a detour that's cheaper than the direct move
+        let path = dijkstra(
+            'a',
+            |&s| match s {
+                'a' => vec![('b', 1), ('c', 10)],
+                'b' => vec![('c', 2)],
+                _ => vec![],
+            },
+            |&s| s == 'c',
+        )
+        .unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.states(), vec!['a', 'b', 'c']);
+    }
+
+    #[test]
+    fn test_astar() {
+        // Manhattan distance weighted by the cheapest move never overestimates
+        let heuristic = |&(x, y): &(u64, u64)| (3 - x) + (3 - y);
+        let path = astar((0, 0), weighted_neighbors, heuristic, |&s| s == (3, 3)).unwrap();
+        assert_eq!(path.cost, 33);
+
+        let plain = dijkstra((0, 0), weighted_neighbors, |&s| s == (3, 3)).unwrap();
+        assert!(path.explored() <= plain.explored());
+
+        assert!(astar((0, 0), weighted_neighbors, heuristic, |&s| s == (9, 9)).is_none());
+    }
+
+    #[test]
+    fn test_dijkstra_all() {
+        // two equally cheap ways around a diamond, and a dearer third
+        let neighbors = |&s: &char| match s {
+            'a' => vec![('b', 1), ('c', 1), ('e', 5)],
+            'b' | 'c' => vec![('d', 1)],
+            'e' => vec![('d', 1)],
+            _ => vec![],
+        };
+        let paths = dijkstra_all('a', neighbors, |&s| s == 'd').unwrap();
+        assert_eq!(paths.cost, 2);
+        assert_eq!(paths.ends, vec!['d']);
+        assert_eq!(paths.states(), HashSet::from(['a', 'b', 'c', 'd']));
+
+        assert!(dijkstra_all('a', neighbors, |&s| s == 'z').is_none());
+    }
+}
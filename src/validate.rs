@@ -0,0 +1,381 @@
+//! `validate`, which checks an input against the shape its day expects and says what's wrong where, without solving
+//! anything. Each day describes its input as a [`Grammar`]; lines within it are described by patterns like
+//! `"Button A: X+{n}, Y+{n}"`, where the parts in braces are fields:
+//!
+//! - `{n}` a number, `{i}` a number that might be negative, `{d}` a single digit, `{w}` a word of letters
+//! - `{[a-z0-9]}` a run of the characters in the brackets, with ranges like a regex
+//! - any of those followed by a separator and `...`, like `{n,...}` or `{[a-z], ...}`, for a list of them
+
+use crate::advent::Error;
+
+/// Past this many problems an input is clearly the wrong file, or for the wrong day, so there's no use listing the rest.
+pub const MAX_DIAGNOSTICS: usize = 20;
+
+pub enum Grammar {
+    /// Anything goes, leaving it to `parse` to object.
+    Text,
+    /// Every line matches the pattern.
+    Lines(&'static str),
+    /// Exactly one line for each pattern, in order.
+    Block(&'static [&'static str]),
+    Grid(Cells),
+    /// Blank-line-separated sections, one for each grammar, in order.
+    Sections(&'static [Grammar]),
+    /// Any number of blank-line-separated sections, all matching the grammar.
+    Repeated(&'static Grammar),
+}
+
+/// A rectangle of single-character cells.
+pub struct Cells {
+    /// The characters allowed, with ranges like `a-z`.
+    pub allowed: &'static str,
+    /// Characters (any of those in each string) that must appear an exact number of times, like the one start.
+    pub exactly: &'static [(&'static str, usize)],
+    /// Width and height, if they're fixed.
+    pub size: Option<(usize, usize)>,
+}
+
+impl Grammar {
+    /// Every problem with `input`, at most one per line.
+    pub fn check(&self, input: &str) -> Vec<Error> {
+        let lines: Vec<(usize, &str)> = input.lines().enumerate().collect();
+        let mut problems = Vec::new();
+        self.check_lines(&lines, &mut problems);
+        problems
+    }
+
+    fn check_lines(&self, lines: &[(usize, &str)], problems: &mut Vec<Error>) {
+        match self {
+            Grammar::Text => {}
+            Grammar::Lines(pattern) => {
+                let pattern = Pattern::new(pattern);
+                if lines.is_empty() {
+                    problems.push(Error::parse("expected at least one line, found none"));
+                }
+                for (y, line) in lines {
+                    problems.extend(pattern.check(line).map(|e| e.at_line(*y)));
+                }
+            }
+            Grammar::Block(patterns) => {
+                for (i, pattern) in patterns.iter().enumerate() {
+                    match lines.get(i) {
+                        Some((y, line)) => problems.extend(Pattern::new(pattern).check(line).map(|e| e.at_line(*y))),
+                        None => {
+                            let after = lines.last().map_or(0, |(y, _)| y + 1);
+                            problems.push(Error::parse(format!("expected a line like {:?}, found none", pattern)).at_line(after));
+                            return;
+                        }
+                    }
+                }
+                if let Some((y, line)) = lines.get(patterns.len()) {
+                    problems.push(Error::parse(format!("expected {} lines here, found another: {:?}", patterns.len(), line)).at_line(*y));
+                }
+            }
+            Grammar::Grid(cells) => cells.check(lines, problems),
+            Grammar::Sections(grammars) => {
+                let sections = sections(lines);
+                for (i, grammar) in grammars.iter().enumerate() {
+                    match sections.get(i) {
+                        Some(section) => grammar.check_lines(section, problems),
+                        None => {
+                            let after = lines.last().map_or(0, |(y, _)| y + 1);
+                            problems.push(
+                                Error::parse(format!(
+                                    "expected {} sections separated by blank lines, found {}",
+                                    grammars.len(),
+                                    sections.len()
+                                ))
+                                .at_line(after),
+                            );
+                            return;
+                        }
+                    }
+                }
+                if let Some(extra) = sections.get(grammars.len()) {
+                    problems.push(
+                        Error::parse(format!("expected {} sections separated by blank lines, found more", grammars.len()))
+                            .at_line(extra[0].0),
+                    );
+                }
+            }
+            Grammar::Repeated(grammar) => {
+                for section in sections(lines) {
+                    grammar.check_lines(&section, problems);
+                }
+            }
+        }
+    }
+}
+
+/// Splits lines into runs between blank lines.
+fn sections<'a>(lines: &[(usize, &'a str)]) -> Vec<Vec<(usize, &'a str)>> {
+    let mut sections = vec![Vec::new()];
+    for (y, line) in lines {
+        if line.trim().is_empty() {
+            if !sections.last().unwrap().is_empty() {
+                sections.push(Vec::new());
+            }
+        } else {
+            sections.last_mut().unwrap().push((*y, *line));
+        }
+    }
+    sections.retain(|section| !section.is_empty());
+    sections
+}
+
+impl Cells {
+    fn check(&self, lines: &[(usize, &str)], problems: &mut Vec<Error>) {
+        let allowed = CharSet::new(self.allowed);
+        let (width, height) = match self.size {
+            Some(size) => size,
+            None => (lines.first().map_or(0, |(_, line)| line.len()), lines.len()),
+        };
+
+        if lines.len() != height {
+            let at = lines.first().map_or(0, |(y, _)| *y);
+            problems.push(Error::parse(format!("expected a grid {} rows tall, found {}", height, lines.len())).at_line(at));
+        }
+
+        let mut found = vec![Vec::new(); self.exactly.len()];
+        for (y, line) in lines {
+            if line.len() != width {
+                problems.push(Error::parse(format!("expected a row {} wide, found {}", width, line.len())).at_line(*y));
+            }
+            for (x, c) in line.chars().enumerate() {
+                if !allowed.contains(c) {
+                    problems.push(Error::parse(format!("expected any of {:?}, found {:?}", self.allowed, c)).at(*y, x));
+                }
+                for ((chars, _), found) in self.exactly.iter().zip(found.iter_mut()) {
+                    if chars.contains(c) {
+                        found.push((*y, x));
+                    }
+                }
+            }
+        }
+
+        for ((chars, count), found) in self.exactly.iter().zip(found) {
+            if found.len() == *count {
+                continue;
+            }
+            let what = if chars.len() == 1 {
+                format!("{:?}", chars.chars().next().unwrap())
+            } else {
+                format!("any of {:?}", chars)
+            };
+            let problem = match found.get(*count) {
+                // pointing at the first one too many, and listing the rest since any of them could be the mistake
+                Some((y, x)) => {
+                    let all: Vec<String> = found.iter().map(|(y, x)| format!("{}:{}", y + 1, x + 1)).collect();
+                    let message = format!(
+                        "expected exactly {} of {}, found {}, at {}",
+                        count,
+                        what,
+                        found.len(),
+                        all.join(", ")
+                    );
+                    Error::parse(message).at(*y, *x)
+                }
+                None => Error::parse(format!("expected exactly {} of {}, found {}", count, what, found.len()))
+                    .at_line(lines.first().map_or(0, |(y, _)| *y)),
+            };
+            problems.push(problem);
+        }
+    }
+}
+
+enum Kind {
+    Number,
+    Signed,
+    Digit,
+    Word,
+    Set(CharSet),
+}
+
+impl Kind {
+    fn describe(&self) -> String {
+        match self {
+            Kind::Number => "a number".to_string(),
+            Kind::Signed => "a number".to_string(),
+            Kind::Digit => "a digit".to_string(),
+            Kind::Word => "a word".to_string(),
+            Kind::Set(set) => format!("any of {:?}", set.source),
+        }
+    }
+
+    /// How many bytes at the start of `text` this matches, if any.
+    fn matches(&self, text: &str) -> Option<usize> {
+        let run = |pred: &dyn Fn(char) -> bool| text.find(|c: char| !pred(c)).unwrap_or(text.len());
+        let len = match self {
+            Kind::Number => run(&|c| c.is_ascii_digit()),
+            Kind::Signed => {
+                let sign = usize::from(text.starts_with('-'));
+                let digits = text[sign..].find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len() - sign);
+                if digits == 0 {
+                    0
+                } else {
+                    sign + digits
+                }
+            }
+            Kind::Digit => usize::from(text.starts_with(|c: char| c.is_ascii_digit())),
+            Kind::Word => run(&|c| c.is_ascii_alphabetic()),
+            Kind::Set(set) => run(&|c| set.contains(c)),
+        };
+        (len > 0).then_some(len)
+    }
+}
+
+enum Token {
+    Literal(String),
+    Field { kind: Kind, separator: Option<String> },
+}
+
+struct Pattern {
+    tokens: Vec<Token>,
+}
+
+impl Pattern {
+    /// Patterns are written by hand in each day, so a malformed one is a bug rather than something to report.
+    fn new(pattern: &str) -> Self {
+        let mut tokens = Vec::new();
+        let mut rest = pattern;
+        while !rest.is_empty() {
+            match rest.find('{') {
+                Some(0) => {
+                    let end = rest.find('}').unwrap_or_else(|| panic!("unclosed field in {:?}", pattern));
+                    let field = &rest[1..end];
+                    let (kind, after) = if let Some(set) = field.strip_prefix('[') {
+                        let close = set.find(']').unwrap_or_else(|| panic!("unclosed set in {:?}", pattern));
+                        (Kind::Set(CharSet::new(&set[..close])), &set[close + 1..])
+                    } else {
+                        let kind = match &field[..1] {
+                            "n" => Kind::Number,
+                            "i" => Kind::Signed,
+                            "d" => Kind::Digit,
+                            "w" => Kind::Word,
+                            other => panic!("unknown field {:?} in {:?}", other, pattern),
+                        };
+                        (kind, &field[1..])
+                    };
+                    let separator = after.strip_suffix("...").map(str::to_string);
+                    tokens.push(Token::Field { kind, separator });
+                    rest = &rest[end + 1..];
+                }
+                Some(start) => {
+                    tokens.push(Token::Literal(rest[..start].to_string()));
+                    rest = &rest[start..];
+                }
+                None => {
+                    tokens.push(Token::Literal(rest.to_string()));
+                    rest = "";
+                }
+            }
+        }
+        Pattern { tokens }
+    }
+
+    /// The first place `line` strays from the pattern, if it does; there's no telling where it would be back on track.
+    fn check(&self, line: &str) -> Option<Error> {
+        let mut pos = 0;
+        for token in &self.tokens {
+            match token {
+                Token::Literal(literal) => {
+                    let same = line[pos..].bytes().zip(literal.bytes()).take_while(|(a, b)| a == b).count();
+                    if same < literal.len() {
+                        let problem = format!("expected {:?}, found {}", &literal[same..], found(&line[pos + same..]));
+                        return Some(Error::parse(problem).at(0, pos + same));
+                    }
+                    pos += literal.len();
+                }
+                Token::Field { kind, separator } => {
+                    let Some(len) = kind.matches(&line[pos..]) else {
+                        return Some(Error::parse(format!("expected {}, found {}", kind.describe(), found(&line[pos..]))).at(0, pos));
+                    };
+                    pos += len;
+
+                    if let Some(separator) = separator {
+                        while line[pos..].starts_with(separator.as_str()) {
+                            pos += separator.len();
+                            let Some(len) = kind.matches(&line[pos..]) else {
+                                let problem = format!("expected {} after {:?}, found {}", kind.describe(), separator, found(&line[pos..]));
+                                return Some(Error::parse(problem).at(0, pos));
+                            };
+                            pos += len;
+                        }
+                    }
+                }
+            }
+        }
+
+        (pos < line.len()).then(|| Error::parse(format!("expected the end of the line, found {}", found(&line[pos..]))).at(0, pos))
+    }
+}
+
+/// What's at the start of `rest`, for saying what was found instead of what was expected.
+fn found(rest: &str) -> String {
+    match rest.chars().next() {
+        Some(c) => format!("{:?}", c),
+        None => "the end of the line".to_string(),
+    }
+}
+
+struct CharSet {
+    source: String,
+    ranges: Vec<(char, char)>,
+}
+
+impl CharSet {
+    fn new(source: &str) -> Self {
+        let chars: Vec<char> = source.chars().collect();
+        let mut ranges = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            if i + 2 < chars.len() && chars[i + 1] == '-' {
+                ranges.push((chars[i], chars[i + 2]));
+                i += 3;
+            } else {
+                ranges.push((chars[i], chars[i]));
+                i += 1;
+            }
+        }
+        CharSet {
+            source: source.to_string(),
+            ranges,
+        }
+    }
+
+    fn contains(&self, c: char) -> bool {
+        self.ranges.iter().any(|(low, high)| (*low..=*high).contains(&c))
+    }
+}
+
+/// A problem as a compiler would show it: where it is, what's wrong, and the line with a marker under the spot.
+pub fn describe(path: &str, input: &str, problem: &Error) -> String {
+    let mut out = String::from(path);
+    if let Some(line) = problem.line {
+        out.push_str(&format!(":{}", line));
+        if let Some(column) = problem.column {
+            out.push_str(&format!(":{}", column));
+        }
+    }
+
+    // the position's already been given, compiler style
+    let message = Error {
+        day: None,
+        line: None,
+        column: None,
+        ..problem.clone()
+    };
+    out.push_str(&format!(": {}", message));
+
+    if let Some(text) = problem
+        .line
+        .and_then(|line| input.lines().nth(line - 1))
+        .filter(|text| !text.is_empty())
+    {
+        out.push_str(&format!("\n    {}", text));
+        if let Some(column) = problem.column {
+            out.push_str(&format!("\n    {}^", " ".repeat(column - 1)));
+        }
+    }
+    out
+}
@@ -0,0 +1,213 @@
+//! `--watch`, which polls a day's source, input and sample, and on every change rebuilds, re-runs the day in a fresh
+//! process and shows how the answers and timings moved since the previous run.
+
+use advent_2024::advent::{Day, Input, Options};
+use prettytable::{format, row, Table};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// What a run of the day came up with, read back from its `--format csv` output.
+#[derive(Default)]
+struct Run {
+    parse_ns: Option<u128>,
+    parts: [Option<(String, u128)>; 2],
+    check: String,
+    error: String,
+}
+
+pub fn watch(day: Day, options: &Options) -> ! {
+    // resolved up front, because once cargo replaces the binary this process's own path points at the deleted file
+    let exe = env::current_exe().expect("can't find the running binary to re-run it");
+
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let mut watched = vec![root.join(format!("src/year{}/day{:02}.rs", options.year, day.number))];
+    for input in [Input::Sample, Input::Real] {
+        let path = PathBuf::from(Day { input, ..day }.path(options));
+        if !watched.contains(&path) {
+            watched.push(path);
+        }
+    }
+
+    println!(
+        "Watching {}",
+        watched.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join(", ")
+    );
+
+    let mut seen = Vec::new();
+    let mut previous: Option<Run> = None;
+    loop {
+        let stamps: Vec<Option<SystemTime>> = watched
+            .iter()
+            .map(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+            .collect();
+
+        if stamps != seen {
+            let changed: Vec<_> = watched
+                .iter()
+                .zip(&stamps)
+                .enumerate()
+                .filter(|(i, (_, stamp))| seen.get(*i) != Some(stamp))
+                .map(|(_, (path, _))| path.display().to_string())
+                .collect();
+            seen = stamps;
+
+            if previous.is_some() {
+                println!("\nChanged: {}", changed.join(", "));
+            }
+
+            if rebuild() {
+                let run = run_day(&exe);
+                print_run(&run, previous.as_ref());
+                previous = Some(run);
+            }
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Builds the binary the same way as the one that's running, letting cargo's own output say what went wrong.
+fn rebuild() -> bool {
+    let mut cargo = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+    cargo
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["build", "--quiet", "--bin", env!("CARGO_BIN_NAME")]);
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+    if cfg!(feature = "alloc-stats") {
+        cargo.args(["--features", "alloc-stats"]);
+    }
+
+    match cargo.status() {
+        Ok(status) if status.success() => true,
+        Ok(_) => {
+            println!("Build failed, waiting for the next change");
+            false
+        }
+        Err(e) => {
+            println!("Couldn't run cargo: {}", e);
+            false
+        }
+    }
+}
+
+/// Runs the freshly built binary with this process's arguments, minus the ones that only make sense here.
+fn run_day(exe: &Path) -> Run {
+    let mut args = Vec::new();
+    let mut given = env::args().skip(1);
+    while let Some(arg) = given.next() {
+        match arg.as_str() {
+            "--watch" => {}
+            "--format" => {
+                given.next();
+            }
+            _ => args.push(arg),
+        }
+    }
+    args.extend(["--format".to_string(), "csv".to_string()]);
+
+    match Command::new(exe).args(&args).output() {
+        Ok(output) => read_run(&output),
+        Err(e) => Run {
+            error: e.to_string(),
+            ..Run::default()
+        },
+    }
+}
+
+fn read_run(output: &Output) -> Run {
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let Some(fields) = stdout.lines().nth(1).map(split_csv) else {
+        // it didn't get as far as printing a row, so whatever it said on stderr is the story
+        return Run {
+            error: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ..Run::default()
+        };
+    };
+
+    let field = |i: usize| fields.get(i).cloned().unwrap_or_default();
+    let part = |answer: usize, ns: usize| {
+        let ns = field(ns).parse().ok()?;
+        Some((field(answer), ns))
+    };
+
+    Run {
+        parse_ns: field(2).parse().ok(),
+        parts: [part(3, 4), part(5, 6)],
+        check: field(7),
+        error: field(8),
+    }
+}
+
+/// Splits a line of the CSV that `output::csv` writes, where fields are only quoted when they have to be.
+fn split_csv(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+fn print_run(run: &Run, previous: Option<&Run>) {
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    table.set_titles(row!["Part", "Result", "Was", "Time", "Was", "Change"]);
+
+    let time = |ns: Option<u128>| ns.map_or(String::new(), |ns| format!("{:?}", Duration::from_nanos(ns as u64)));
+    let change = |now: Option<u128>, was: Option<u128>| match (now, was) {
+        (Some(now), Some(was)) if was > 0 => format!("{:+.1}%", (now as f64 - was as f64) / was as f64 * 100.0),
+        _ => String::new(),
+    };
+
+    let was_parse = previous.and_then(|p| p.parse_ns);
+    table.add_row(row![
+        r -> "Parse",
+        "",
+        "",
+        time(run.parse_ns),
+        time(was_parse),
+        r -> change(run.parse_ns, was_parse)
+    ]);
+
+    for (i, part) in run.parts.iter().enumerate() {
+        let was = previous.and_then(|p| p.parts[i].as_ref());
+        let (answer, ns) = match part {
+            Some((answer, ns)) => (answer.as_str(), Some(*ns)),
+            None => ("", None),
+        };
+
+        // only show the old answer when it's different, so a change stands out
+        let was_answer = match was {
+            Some((was, _)) if was != answer => was.as_str(),
+            _ => "",
+        };
+        let was_ns = was.map(|(_, ns)| *ns);
+
+        table.add_row(row![r -> i + 1, answer, was_answer, time(ns), time(was_ns), r -> change(ns, was_ns)]);
+    }
+
+    table.printstd();
+    if !run.check.is_empty() && run.check != "unchecked" {
+        println!("Check: {}", run.check);
+    }
+    if !run.error.is_empty() {
+        println!("Error: {}", run.error);
+    }
+}
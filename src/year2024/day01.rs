@@ -0,0 +1,94 @@
+use crate::advent::{column_of, parse_num, Error, Log, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::validate::Grammar;
+use std::collections::BTreeMap;
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
+        let mut list_a = Vec::with_capacity(1000);
+        let mut list_b = Vec::with_capacity(1000);
+
+        for (y, line) in input.lines().enumerate() {
+            let numbers: Vec<i32> = line
+                .split_whitespace()
+                .map(|s| parse_num(s).map_err(|e| e.at(y, column_of(line, s))))
+                .collect::<Result<_>>()?;
+            if numbers.len() == 2 {
+                list_a.push(numbers[0]);
+                list_b.push(numbers[1]);
+            } else {
+                return Err(Error::parse(format!("expected two numbers, found {}", numbers.len())).at_line(y));
+            }
+        }
+
+        list_a.sort_unstable();
+        list_b.sort_unstable();
+
+        Ok((list_a, list_b))
+    }
+
+    fn part_1(&self, (list_a, list_b): &mut Self::Input, _log: &Log) -> Result<String> {
+        let r = list_a.iter().zip(list_b.iter()).fold(0, |sum, (a, b)| sum + (b - a).unsigned_abs());
+        Ok(r.to_string())
+    }
+
+    fn part_2(&self, (list_a, list_b): &mut Self::Input, _log: &Log) -> Result<String> {
+        let mut hash = BTreeMap::new();
+        for n in list_b.iter() {
+            hash.entry(n).and_modify(|e| *e += 1).or_insert(1);
+        }
+
+        let sum_similarity = list_a.iter().fold(0, |sum, val| {
+            let similarity = hash.get(val).unwrap_or(&0);
+            sum + similarity * val
+        });
+
+        Ok(sum_similarity.to_string())
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("2756096", "23117829")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("11"), Some("31"))
+    }
+
+    fn name(&self) -> &'static str {
+        "Historian Hysteria"
+    }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Lines("{n}   {n}")
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "lines",
+            default_size: 1000,
+            generate,
+        })
+    }
+}
+
+/// Two columns of five-digit numbers, with the right one borrowing from the left often enough that part 2 has
+/// something to count.
+fn generate(rng: &mut Rng, lines: usize) -> String {
+    let left: Vec<i64> = (0..lines).map(|_| rng.between(10000, 99999)).collect();
+    let mut right: Vec<i64> = (0..lines)
+        .map(|_| {
+            if rng.chance(0.3) {
+                *rng.pick(&left)
+            } else {
+                rng.between(10000, 99999)
+            }
+        })
+        .collect();
+    rng.shuffle(&mut right);
+
+    left.iter().zip(&right).map(|(l, r)| format!("{}   {}\n", l, r)).collect()
+}
@@ -1,41 +1,60 @@
-use crate::advent::Solver;
+use crate::advent::{column_of, parse_num, Log, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::validate::Grammar;
+use itertools::Itertools;
 
 pub struct Day02;
 
 impl Solver for Day02 {
     type Input = Vec<Vec<i8>>;
 
-    fn parse(&self, input: &str, _: bool) -> Self::Input {
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
         let mut reports = Vec::with_capacity(1000);
-        for line in input.lines() {
+        for (y, line) in input.lines().enumerate() {
             let levels: Vec<i8> = line
                 .split_whitespace()
-                .map(|s| s.parse::<i8>().expect("Text should be a number"))
-                .collect();
+                .map(|s| parse_num(s).map_err(|e| e.at(y, column_of(line, s))))
+                .collect::<Result<_>>()?;
             reports.push(levels);
         }
-        reports
+        Ok(reports)
     }
 
-    fn part_1(&self, input: &mut Self::Input) -> String {
-        input.iter().filter(|report| is_report_safe(report, None)).count().to_string()
+    fn part_1(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        Ok(input.iter().filter(|report| is_report_safe(report, None)).count().to_string())
     }
 
-    fn part_2(&self, input: &mut Self::Input) -> String {
-        input
+    fn part_2(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        Ok(input
             .iter()
             .filter(|report| report.iter().enumerate().any(|(i, _)| is_report_safe(report, Some(i))))
             .count()
-            .to_string()
+            .to_string())
     }
 
     fn expected(&self) -> (&'static str, &'static str) {
         ("332", "398")
     }
 
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("2"), Some("4"))
+    }
+
     fn name(&self) -> &'static str {
         "Red-Nosed Reports"
     }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Lines("{n ...}")
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "reports",
+            default_size: 1000,
+            generate,
+        })
+    }
 }
 
 fn is_report_safe(report: &[i8], exclude_index: Option<usize>) -> bool {
@@ -90,3 +109,26 @@ enum ReportState {
     Up(i8),
     Down(i8),
 }
+
+/// Reports that go steadily up or down, about half of them with one level knocked out of line.
+fn generate(rng: &mut Rng, reports: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..reports {
+        let direction = if rng.chance(0.5) { 1 } else { -1 };
+        let mut level = rng.between(25, 75);
+        let mut levels = vec![level];
+        for _ in 1..rng.between(5, 8) {
+            level += direction * rng.between(1, 3);
+            levels.push(level);
+        }
+
+        if rng.chance(0.5) {
+            let i = rng.below(levels.len());
+            levels[i] = rng.between(1, 99);
+        }
+
+        out.push_str(&levels.iter().join(" "));
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,123 @@
+use crate::advent::{parse_num, Error, Log, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use regex::Regex;
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Input = Vec<Op>;
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
+        let re = Regex::new(r"(mul\((\d+),(\d+)\)|do\(\)|don't\(\))").unwrap();
+        re.captures_iter(input)
+            .map(|cap| match cap.get(1) {
+                Some(m) => match m.as_str() {
+                    s if s.starts_with("mul") => {
+                        // the regex only matches digits, so the only way these fail is overflow
+                        let op1 = parse_num(&cap[2]).map_err(|e| located(e, input, m.start()))?;
+                        let op2 = parse_num(&cap[3]).map_err(|e| located(e, input, m.start()))?;
+                        Ok(Op::Mul(op1, op2))
+                    }
+                    "do()" => Ok(Op::Do),
+                    "don't()" => Ok(Op::Dont),
+                    _ => unreachable!("the regex only matches these three"),
+                },
+                None => unreachable!("group 1 is the whole match"),
+            })
+            .collect()
+    }
+
+    fn part_1(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        Ok(sum_all(input, false).to_string())
+    }
+
+    fn part_2(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        Ok(sum_all(input, true).to_string())
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("189600467", "107069718")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("161"), Some("48"))
+    }
+
+    fn name(&self) -> &'static str {
+        "Mull It Over"
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "instructions",
+            default_size: 780,
+            generate,
+        })
+    }
+}
+
+fn located(e: Error, input: &str, offset: usize) -> Error {
+    let line = input[..offset].matches('\n').count();
+    let column = offset - input[..offset].rfind('\n').map_or(0, |i| i + 1);
+    e.at(line, column)
+}
+
+fn sum_all(ops: &[Op], can_disable: bool) -> u64 {
+    let mut sum: u64 = 0;
+    let mut doing = true;
+    for op in ops {
+        match op {
+            Op::Mul(op1, op2) => {
+                let more = op1 * op2;
+                if doing || !can_disable {
+                    sum += more;
+                }
+            }
+            Op::Do => doing = true,
+            Op::Dont => doing = false,
+        }
+    }
+
+    sum
+}
+
+#[derive(Debug)]
+pub enum Op {
+    Mul(u64, u64),
+    Do,
+    Dont,
+}
+
+/// Instructions scattered through junk, some of which looks enough like an instruction to catch out a sloppy parser.
+fn generate(rng: &mut Rng, instructions: usize) -> String {
+    const JUNK: &[&str] = &[
+        "!", "@", "#", "$", "%", "^", "&", "*", "(", ")", "[", "]", "{", "}", "<", ">", ",", ";", ":", "'", "?", "/", " ", "+", "-", "~",
+        "mul", "mul(", "do", "don't", "from()", "what()", "why()", "select()",
+    ];
+
+    let mut out = String::new();
+    let line_length = instructions.div_ceil(6).max(1);
+    for i in 0..instructions {
+        for _ in 0..rng.below(6) {
+            let junk = rng.pick(JUNK);
+            out.push_str(junk);
+        }
+
+        let (a, b) = (rng.between(1, 999), rng.between(1, 999));
+        match rng.below(10) {
+            0 => out.push_str("do()"),
+            1 => out.push_str("don't()"),
+            2 => out.push_str(&format!("mul[{},{}]", a, b)),
+            3 => out.push_str(&format!("mul({}, {})", a, b)),
+            _ => out.push_str(&format!("mul({},{})", a, b)),
+        }
+
+        if (i + 1) % line_length == 0 {
+            out.push('\n');
+        }
+    }
+    if !out.ends_with('\n') {
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,94 @@
+use crate::advent::{Log, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::grid::{Grid, DELTAS_8};
+use crate::validate::{Cells, Grammar};
+
+pub struct Day04;
+impl Solver for Day04 {
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
+        Grid::parse(input, |c, _, _| Ok(c))
+    }
+
+    fn part_1(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        const MAS: &str = "MAS";
+
+        let mut xmases = 0;
+        for pos in input.positions(|c| *c == 'X') {
+            for (dx, dy) in DELTAS_8 {
+                let mut pos = pos;
+                for expected_next in MAS.chars() {
+                    if let Some(next_pos) = input.offset(pos, dx, dy) {
+                        let found = input[next_pos];
+                        if found == expected_next {
+                            if found == 'S' {
+                                xmases += 1;
+                            } else {
+                                pos = next_pos;
+                                continue;
+                            }
+                        }
+                    }
+                    break;
+                }
+            }
+        }
+
+        Ok(xmases.to_string())
+    }
+
+    fn part_2(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        fn is_mas(grid: &Grid<char>, tuple: (Option<usize>, Option<usize>)) -> bool {
+            if let (Some(first), Some(second)) = tuple {
+                let (c1, c2) = (grid[first], grid[second]);
+                return c1 == 'S' && c2 == 'M' || c1 == 'M' && c2 == 'S';
+            }
+            false
+        }
+
+        let xmases = input
+            .positions(|c| *c == 'A')
+            .filter(|pos| {
+                let right_down_to_left_up = (input.offset(*pos, 1, 1), input.offset(*pos, -1, -1));
+                let left_down_to_right_up = (input.offset(*pos, -1, 1), input.offset(*pos, 1, -1));
+                is_mas(input, right_down_to_left_up) && is_mas(input, left_down_to_right_up)
+            })
+            .count();
+
+        Ok(xmases.to_string())
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("2599", "1948")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("18"), Some("9"))
+    }
+
+    fn name(&self) -> &'static str {
+        "Ceres Search"
+    }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Grid(Cells {
+            allowed: "XMAS",
+            exactly: &[],
+            size: None,
+        })
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "rows and columns",
+            default_size: 140,
+            generate,
+        })
+    }
+}
+
+fn generate(rng: &mut Rng, side: usize) -> String {
+    let side = side.max(1);
+    Grid::from_cells(side, (0..side * side).map(|_| *rng.pick(&['X', 'M', 'A', 'S'])).collect()).to_string()
+}
@@ -0,0 +1,142 @@
+use crate::advent::{column_of, parse_num, Error, Log, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::validate::Grammar;
+use hashbrown::{HashMap, HashSet};
+use itertools::Itertools;
+
+pub struct Day05;
+impl Solver for Day05 {
+    type Input = Update;
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
+        let mut rules = HashSet::new();
+        let mut pages = Vec::new();
+        for (y, line) in input.lines().enumerate() {
+            let num = |s: &str| parse_num(s).map_err(|e| e.at(y, column_of(line, s)));
+            if line.contains(",") {
+                let parts = line.split(",");
+                let lil_pages: Vec<usize> = parts.map(num).collect::<Result<_>>()?;
+                if lil_pages.is_empty() || lil_pages.len().is_multiple_of(2) {
+                    return Err(Error::parse("expected an odd number of pages").at_line(y));
+                }
+                pages.push(lil_pages);
+            } else if let Some((first, second)) = line.split_once("|") {
+                rules.insert((num(first)?, num(second)?));
+            }
+        }
+
+        Ok(Update { rules, pages })
+    }
+
+    fn part_1(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        Ok(input
+            .pages
+            .iter()
+            .filter(|page_set| is_correct(page_set, &input.rules))
+            .map(|page_set| middle_page(page_set))
+            .sum::<usize>()
+            .to_string())
+    }
+
+    fn part_2(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        Ok(input
+            .pages
+            .iter_mut()
+            .filter(|page_set| !is_correct(page_set, &input.rules))
+            .map(|page_set| {
+                page_set.sort_by(|a, b| {
+                    if input.rules.contains(&(*a, *b)) {
+                        std::cmp::Ordering::Less
+                    } else if input.rules.contains(&(*b, *a)) {
+                        std::cmp::Ordering::Greater
+                    } else {
+                        std::cmp::Ordering::Equal
+                    }
+                });
+
+                middle_page(page_set)
+            })
+            .sum::<usize>()
+            .to_string())
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("5948", "3062")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("143"), Some("123"))
+    }
+
+    fn name(&self) -> &'static str {
+        "Print Queue"
+    }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Sections(&[Grammar::Lines("{n}|{n}"), Grammar::Lines("{n,...}")])
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "updates",
+            default_size: 200,
+            generate,
+        })
+    }
+}
+
+fn is_correct(page_set: &[usize], rules: &HashSet<(usize, usize)>) -> bool {
+    let page_map = page_set.iter().enumerate().map(|(i, p)| (p, i)).collect::<HashMap<_, _>>();
+    for rule in rules {
+        if let Some(first) = page_map.get(&rule.0) {
+            if let Some(second) = page_map.get(&rule.1) {
+                if first > second {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+fn middle_page(page_set: &[usize]) -> usize {
+    page_set[(page_set.len() - 1) / 2]
+}
+
+#[derive(Debug)]
+pub struct Update {
+    rules: HashSet<(usize, usize)>,
+    pages: Vec<Vec<usize>>,
+}
+
+/// Like the real thing, the rules put 49 pages in a circle, each coming before the 24 after it, so they're no total
+/// order; each update is taken from within a stretch of the circle where they are, so it has a right order.
+fn generate(rng: &mut Rng, updates: usize) -> String {
+    const PAGES: usize = 49;
+    let mut pages: Vec<usize> = (11..100).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(PAGES);
+
+    let mut rules: Vec<String> = (0..PAGES)
+        .flat_map(|i| (1..=PAGES / 2).map(move |j| (i, (i + j) % PAGES)))
+        .map(|(i, j)| format!("{}|{}\n", pages[i], pages[j]))
+        .collect();
+    rng.shuffle(&mut rules);
+
+    let mut out = rules.concat();
+    out.push('\n');
+    for _ in 0..updates {
+        let start = rng.below(PAGES);
+        let mut stretch: Vec<usize> = (0..=PAGES / 2).map(|i| (start + i) % PAGES).collect();
+        rng.shuffle(&mut stretch);
+        stretch.truncate(rng.between(2, 11) as usize * 2 + 1);
+
+        // in order for about half of them, to give both parts something to do
+        if rng.chance(0.5) {
+            stretch.sort_by_key(|&i| (i + PAGES - start) % PAGES);
+        }
+        out.push_str(&stretch.iter().map(|&i| pages[i]).join(","));
+        out.push('\n');
+    }
+    out
+}
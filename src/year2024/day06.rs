@@ -0,0 +1,232 @@
+use crate::advent::{Error, Log, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::grid::{Dir, Grid};
+use crate::validate::{Cells, Grammar};
+use std::collections::HashSet;
+
+pub struct Day06;
+impl Solver for Day06 {
+    type Input = (Map, Guard);
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
+        let mut guard = None;
+        let map = Grid::parse(input, |c, x, y| {
+            let square = match c {
+                '#' => Square::Obstacle,
+                '.' => Square::Empty,
+                _ => {
+                    let dir = Dir::from_char(c).ok_or_else(|| Error::parse(format!("unexpected {:?}", c)))?;
+                    if guard.is_some() {
+                        return Err(Error::parse("found a second guard"));
+                    }
+                    guard = Some((dir, x, y));
+                    Square::Empty
+                }
+            };
+            Ok((square, Marker { round: 0, dir: Dir::Up }))
+        })?;
+
+        match guard {
+            Some((dir, x, y)) => Ok((map, Guard { dir, pos: (x, y) })),
+            None => Err(Error::parse("no guard found")),
+        }
+    }
+
+    fn part_1(&self, (grid, guard): &mut Self::Input, log: &Log) -> Result<String> {
+        let candidate_pos = part_1(grid, guard.clone(), log)?;
+        Ok((candidate_pos.len() + 1).to_string())
+    }
+
+    fn part_2(&self, (grid, guard): &mut Self::Input, log: &Log) -> Result<String> {
+        let candidate_pos = part_1(grid, guard.clone(), log)?;
+
+        let mut obstacles_that_worked = 0;
+
+        let mut last_pos = guard.pos;
+        let mut last_dir = guard.dir;
+
+        for (i, (pos, dir)) in (1..).zip(candidate_pos) {
+            log.step()?;
+            let (obstacle, cleared) = (grid.index_of(pos.0, pos.1), grid.index_of(last_pos.0, last_pos.1));
+            grid[obstacle].0 = Square::Obstacle;
+            grid[cleared].0 = Square::Empty;
+
+            guard.pos = last_pos;
+            guard.dir = last_dir;
+
+            while let Some(result) = guard.step(grid, i + 1) {
+                match result {
+                    Advancement::Normal => {}
+                    // an obstacle that boxes the guard in works as well as one that sends it round in circles, since
+                    // either way it never leaves, just turning on the spot forever
+                    Advancement::Loop | Advancement::Trapped => {
+                        obstacles_that_worked += 1;
+                        break;
+                    }
+                }
+            }
+
+            last_pos = pos;
+            last_dir = dir;
+        }
+
+        Ok(obstacles_that_worked.to_string())
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("4696", "1443")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("41"), Some("6"))
+    }
+
+    fn name(&self) -> &'static str {
+        "Guard Gallivant"
+    }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Grid(Cells {
+            allowed: ".#^v<>",
+            exactly: &[("^v<>", 1)],
+            size: None,
+        })
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "rows and columns",
+            default_size: 130,
+            generate,
+        })
+    }
+}
+
+/// Where the guard goes on the way out, in order. A guard that never leaves walks until the log's step limit.
+fn part_1(grid: &mut Map, mut guard: Guard, log: &Log) -> Result<Vec<((usize, usize), Dir)>> {
+    let mut visited = HashSet::new();
+    let mut in_order = Vec::new();
+
+    visited.insert(guard.pos);
+
+    while let Some(result) = guard.step(grid, 0) {
+        if result == Advancement::Trapped {
+            return Err(Error::unsolvable("the guard is boxed in by obstacles"));
+        }
+        log.step()?;
+        if visited.insert(guard.pos) {
+            in_order.push((guard.pos, guard.dir));
+        }
+    }
+
+    Ok(in_order)
+}
+
+#[derive(Debug)]
+pub struct Marker {
+    round: usize,
+    dir: Dir,
+}
+
+pub type Map = Grid<(Square, Marker)>;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Square {
+    Obstacle,
+    Empty,
+}
+
+#[derive(Debug, PartialEq)]
+enum Advancement {
+    Normal,
+    Loop,
+    /// Obstacles on all four sides, so the guard can't go anywhere.
+    Trapped,
+}
+
+#[derive(Debug, Clone)]
+pub struct Guard {
+    dir: Dir,
+    /// Kept as coordinates, since stepping those is cheaper than working them out from an index every move.
+    pos: (usize, usize),
+}
+
+impl Guard {
+    fn step(&mut self, grid: &mut Map, round: usize) -> Option<Advancement> {
+        // turning right four times gets back to where it started, so a guard that's still facing an obstacle after
+        // that is boxed in
+        for _ in 0..4 {
+            let (dx, dy) = self.dir.delta();
+            let (x, y) = (self.pos.0 as isize + dx, self.pos.1 as isize + dy);
+            let (square, marker) = grid.get_mut(grid.at(x, y)?)?;
+
+            if let Square::Obstacle = square {
+                self.dir = self.dir.rotate_right();
+                continue;
+            }
+
+            self.pos = (x as usize, y as usize);
+
+            let result = if marker.round == round && marker.dir == self.dir {
+                Advancement::Loop
+            } else {
+                marker.round = round;
+                Advancement::Normal
+            };
+
+            marker.round = round;
+            marker.dir = self.dir;
+
+            return Some(result);
+        }
+
+        Some(Advancement::Trapped)
+    }
+}
+
+/// A lab with obstacles dotted about and the guard somewhere among them, tried again until the guard walks out.
+fn generate(rng: &mut Rng, side: usize) -> String {
+    let side = side.max(2);
+    loop {
+        let mut lab = Grid::from_cells(side, (0..side * side).map(|_| if rng.chance(0.05) { '#' } else { '.' }).collect());
+        let start = loop {
+            let i = rng.below(lab.len());
+            if lab[i] == '.' {
+                break i;
+            }
+        };
+        lab[start] = '^';
+
+        let mut seen = HashSet::new();
+        let (mut at, mut dir) = (start, Dir::Up);
+        let escapes = loop {
+            if !seen.insert((at, dir)) {
+                break false;
+            }
+            match lab.step(at, dir) {
+                None => break true,
+                Some(next) if lab[next] == '#' => dir = dir.rotate_right(),
+                Some(next) => at = next,
+            }
+        };
+
+        if escapes {
+            return lab.to_string();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::advent::Input;
+
+    #[test]
+    fn test_boxing_the_guard_in_counts() {
+        // the guard turns twice to walk out downwards, and an obstacle below it leaves nowhere to go
+        let mut input = Day06.parse(".#.\n#^#\n...\n", &Params::new(Day06.params(), Input::Real)).unwrap();
+        let log = Log::new(6, 0);
+        assert_eq!(Day06.part_1(&mut input, &log).unwrap(), "2");
+        assert_eq!(Day06.part_2(&mut input, &log).unwrap(), "1");
+    }
+}
@@ -1,36 +1,67 @@
-use crate::advent::Solver;
+use crate::advent::{column_of, parse_num, Error, Log, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::validate::Grammar;
+use itertools::Itertools;
 
 pub struct Day07;
 impl Solver for Day07 {
     type Input = Vec<Equation>;
 
-    fn parse(&self, input: &str, _: bool) -> Self::Input {
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
         input
             .lines()
-            .map(|line| {
-                let colon_split = line.split(": ").collect::<Vec<&str>>();
-                let result = colon_split[0].parse::<u64>().unwrap();
-                let args = colon_split[1].split(" ").map(|arg| arg.parse::<u64>().unwrap()).collect();
-                Equation { result, args }
+            .enumerate()
+            .map(|(y, line)| {
+                let num = |s: &str| parse_num(s).map_err(|e| e.at(y, column_of(line, s)));
+                let (result, args) = line.split_once(": ").ok_or_else(|| Error::parse("expected \": \"").at_line(y))?;
+                let result = num(result)?;
+                let args: Vec<u64> = args
+                    .split(" ")
+                    .map(|arg| match num(arg)? {
+                        // working backwards through a multiplication divides by it
+                        0 => Err(Error::parse("expected a positive argument").at(y, column_of(line, arg))),
+                        n => Ok(n),
+                    })
+                    .collect::<Result<_>>()?;
+                if args.len() < 2 {
+                    return Err(Error::parse("expected at least two arguments").at_line(y));
+                }
+                Ok(Equation { result, args })
             })
             .collect()
     }
 
-    fn part_1(&self, input: &mut Self::Input) -> String {
-        try_combos(input, &[Op::Add, Op::Mul]).to_string()
+    fn part_1(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        Ok(try_combos(input, &[Op::Add, Op::Mul]).to_string())
     }
 
-    fn part_2(&self, input: &mut Self::Input) -> String {
-        try_combos(input, &[Op::Add, Op::Mul, Op::Concat]).to_string()
+    fn part_2(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        Ok(try_combos(input, &[Op::Add, Op::Mul, Op::Concat]).to_string())
     }
 
     fn expected(&self) -> (&'static str, &'static str) {
         ("21572148763543", "581941094529163")
     }
 
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("3749"), Some("11387"))
+    }
+
     fn name(&self) -> &'static str {
         "Bridge Repair"
     }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Lines("{n}: {n ...}")
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "equations",
+            default_size: 850,
+            generate,
+        })
+    }
 }
 
 fn try_combos(equations: &[Equation], ops: &[Op]) -> u64 {
@@ -116,6 +147,29 @@ struct Entry<'a> {
     partial_sum: u64,
 }
 
+/// Equations made by putting operators between random numbers, half of them then knocked off by a little.
+fn generate(rng: &mut Rng, equations: usize) -> String {
+    let mut out = Vec::new();
+    while out.len() < equations {
+        let args: Vec<u64> = (0..rng.between(2, 12)).map(|_| rng.between(1, 999) as u64).collect();
+        let result = args[1..].iter().try_fold(args[0], |acc, &arg| match rng.below(3) {
+            0 => acc.checked_add(arg),
+            1 => acc.checked_mul(arg),
+            _ => acc.checked_mul(10u64.pow(arg.ilog10() + 1))?.checked_add(arg),
+        });
+
+        // keep it well clear of overflow, since the solver adds and multiplies on the way to ruling things out
+        let Some(mut result) = result.filter(|&r| r < 1_000_000_000_000_000) else {
+            continue;
+        };
+        if rng.chance(0.5) {
+            result += rng.between(1, 10) as u64;
+        }
+        out.push(format!("{}: {}\n", result, args.iter().join(" ")));
+    }
+    out.concat()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
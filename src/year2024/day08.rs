@@ -1,4 +1,7 @@
-use crate::advent::Solver;
+use crate::advent::{Log, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::grid::Grid;
+use crate::validate::{Cells, Grammar};
 use itertools::Itertools;
 use std::collections::HashMap;
 
@@ -7,36 +10,32 @@ pub struct Day08;
 impl Solver for Day08 {
     type Input = Parsed;
 
-    fn parse(&self, input: &str, _: bool) -> Self::Input {
-        let mut height = 0;
-        let mut width = 0;
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
         let mut antennas = Vec::new();
-
-        for (y, line) in input.lines().enumerate() {
-            if y == 0 {
-                width = line.len();
-            }
-            height += 1;
-            for (x, c) in line.chars().enumerate() {
-                if let 'a'..='z' | 'A'..='Z' | '0'..='9' = c {
-                    antennas.push(Antenna {
-                        freq: c,
-                        pos: Pos::new(x as i64, y as i64),
-                    });
-                }
+        let map = Grid::parse(input, |c, x, y| {
+            if let 'a'..='z' | 'A'..='Z' | '0'..='9' = c {
+                antennas.push(Antenna {
+                    freq: c,
+                    pos: Pos::new(x as i64, y as i64),
+                });
             }
-        }
+            Ok(())
+        })?;
 
         let antennas: HashMap<char, Vec<Antenna>> = antennas.iter().fold(HashMap::new(), |mut map, a| {
             map.entry(a.freq).or_default().push(a.clone());
             map
         });
 
-        Parsed { antennas, width, height }
+        Ok(Parsed {
+            antennas,
+            width: map.width(),
+            height: map.height(),
+        })
     }
 
-    fn part_1(&self, input: &mut Self::Input) -> String {
-        let mut antinode_map = vec![false; input.width * input.height];
+    fn part_1(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        let mut antinode_map = Grid::new(input.width, input.height, false);
         for (_, antennas) in input.antennas.iter() {
             for pair in antennas.iter().combinations(2) {
                 let (a, b) = (pair[0], pair[1]);
@@ -45,23 +44,21 @@ impl Solver for Day08 {
                 let option_2 = Pos::new(2 * b.pos.x - a.pos.x, 2 * b.pos.y - a.pos.y);
 
                 for option in &[option_1, option_2] {
-                    if option.x >= 0 && option.y >= 0 && option.x < input.width as i64 && option.y < input.height as i64 {
-                        if let Some(slot) = antinode_map.get_mut(option.y as usize * input.width + option.x as usize) {
-                            *slot = true;
-                        }
+                    if let Some(i) = antinode_map.at(option.x as isize, option.y as isize) {
+                        antinode_map[i] = true;
                     }
                 }
             }
         }
 
-        antinode_map.iter().filter(|&a| *a).count().to_string()
+        Ok(antinode_map.iter().filter(|&a| *a).count().to_string())
     }
 
-    fn part_2(&self, input: &mut Self::Input) -> String {
+    fn part_2(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
         let formulas: Vec<Resonation> = input
             .antennas
-            .iter()
-            .flat_map(|(_, antennas)| {
+            .values()
+            .flat_map(|antennas| {
                 antennas.iter().combinations(2).map(|pair| {
                     let (a, b) = (pair[0], pair[1]);
                     Resonation {
@@ -88,16 +85,36 @@ impl Solver for Day08 {
             }
         }
 
-        count.to_string()
+        Ok(count.to_string())
     }
 
     fn expected(&self) -> (&'static str, &'static str) {
         ("261", "898")
     }
 
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("14"), Some("34"))
+    }
+
     fn name(&self) -> &'static str {
         "Resonant Collinearity"
     }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Grid(Cells {
+            allowed: ".a-zA-Z0-9",
+            exactly: &[],
+            size: None,
+        })
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "rows and columns",
+            default_size: 50,
+            generate,
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -131,3 +148,20 @@ struct Resonation {
     delta_y: i64,
     anchor: Pos,
 }
+
+/// A handful of antennas on each of about one frequency per 60 squares.
+fn generate(rng: &mut Rng, side: usize) -> String {
+    let side = side.max(1);
+    let mut frequencies: Vec<char> = ('a'..='z').chain('A'..='Z').chain('0'..='9').collect();
+    rng.shuffle(&mut frequencies);
+    frequencies.truncate((side * side / 60).max(1));
+
+    let mut map = Grid::new(side, side, '.');
+    for frequency in frequencies {
+        for _ in 0..rng.between(2, 4) {
+            let i = rng.below(map.len());
+            map[i] = frequency;
+        }
+    }
+    map.to_string()
+}
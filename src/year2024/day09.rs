@@ -1,11 +1,17 @@
-use crate::advent::Solver;
+use crate::advent::{Error, Log, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::validate::Grammar;
 
 pub struct Day09;
 
 impl Solver for Day09 {
     type Input = (Vec<Block>, Vec<Slot>);
 
-    fn parse(&self, input: &str, _: bool) -> Self::Input {
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
+        if let Some((x, c)) = input.trim_end().chars().enumerate().find(|(_, c)| !c.is_ascii_digit()) {
+            return Err(Error::parse(format!("expected a digit, found {:?}", c)).at(0, x));
+        }
+
         let mut chars = input.chars();
         let mut id = 0;
         let mut index = 0;
@@ -39,10 +45,10 @@ impl Solver for Day09 {
             id += 1;
         }
 
-        (blocks, slots)
+        Ok((blocks, slots))
     }
 
-    fn part_1(&self, (_, slots): &mut Self::Input) -> String {
+    fn part_1(&self, (_, slots): &mut Self::Input, _log: &Log) -> Result<String> {
         let mut reversed = slots.iter().rev().enumerate().filter(|(_, slot)| matches!(slot, Slot::File(_)));
 
         let mut checksum = 0;
@@ -70,10 +76,10 @@ impl Solver for Day09 {
             }
         }
 
-        checksum.to_string()
+        Ok(checksum.to_string())
     }
 
-    fn part_2(&self, (blocks, _): &mut Self::Input) -> String {
+    fn part_2(&self, (blocks, _): &mut Self::Input, _log: &Log) -> Result<String> {
         let mut free: Vec<Block> = blocks.iter().filter(|b| matches!(b.slot, Slot::Empty)).cloned().collect();
         let mut offsets: [usize; 9] = [0; 9];
 
@@ -81,6 +87,10 @@ impl Solver for Day09 {
 
         for block in blocks.iter().filter(|b| matches!(b.slot, Slot::File(_))).rev() {
             if let Slot::File(id) = block.slot {
+                // an empty file adds nothing wherever it goes, and has no slot in `offsets`
+                if block.size == 0 {
+                    continue;
+                }
                 let offset = offsets[block.size - 1];
 
                 if offset > block.start {
@@ -111,16 +121,32 @@ impl Solver for Day09 {
             }
         }
 
-        total.to_string()
+        Ok(total.to_string())
     }
 
     fn expected(&self) -> (&'static str, &'static str) {
         ("6344673854800", "6360363199987")
     }
 
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("1928"), Some("2858"))
+    }
+
     fn name(&self) -> &'static str {
         "Disk Fragmenter"
     }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Lines("{[0-9]}")
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "digits",
+            default_size: 19999,
+            generate,
+        })
+    }
 }
 
 fn sum(start: usize, count: usize, id: usize) -> usize {
@@ -140,3 +166,13 @@ pub struct Block {
     pub size: usize,
     pub slot: Slot,
 }
+
+/// An odd number of digits, so the map ends on a file, and no empty files.
+fn generate(rng: &mut Rng, digits: usize) -> String {
+    let mut out: String = (0..digits / 2 * 2 + 1)
+        .map(|i| if i % 2 == 0 { rng.between(1, 9) } else { rng.between(0, 9) })
+        .map(|d| char::from_digit(d as u32, 10).unwrap())
+        .collect();
+    out.push('\n');
+    out
+}
@@ -0,0 +1,133 @@
+use crate::advent::{Error, Log, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::grid::Grid;
+use crate::validate::{Cells, Grammar};
+use std::collections::{BTreeMap, BTreeSet};
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input = Map;
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
+        let heights = Grid::parse(input, |c, _, _| {
+            c.to_digit(10)
+                .map(|d| d as u8)
+                .ok_or_else(|| Error::parse(format!("expected a height, found {:?}", c)))
+        })?;
+
+        if let Some(missing) = (0..=9).find(|n| !heights.iter().any(|h| h == n)) {
+            return Err(Error::parse(format!("no {} anywhere on the map", missing)));
+        }
+
+        Ok(Map { heights })
+    }
+
+    fn part_1(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        let progress = compute(input);
+        Ok(progress.values().fold(0, |nines, agg| nines + agg.zeros.len()).to_string())
+    }
+
+    fn part_2(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        let progress = compute(input);
+        Ok(progress.values().fold(0, |distinct, agg| distinct + agg.perm).to_string())
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("617", "1477")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("36"), Some("81"))
+    }
+
+    fn name(&self) -> &'static str {
+        "Hoof It"
+    }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Grid(Cells {
+            allowed: "0-9",
+            exactly: &[],
+            size: None,
+        })
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "rows and columns",
+            default_size: 45,
+            generate,
+        })
+    }
+}
+
+fn compute(map: &Map) -> BTreeMap<usize, TrailAgg> {
+    let mut progress: BTreeMap<usize, TrailAgg> = map
+        .heights
+        .positions(|h| *h == 0)
+        .map(|coord| (coord, TrailAgg::new(coord)))
+        .collect();
+
+    for i in 1..=9 {
+        let mut next_progress: BTreeMap<usize, TrailAgg> = BTreeMap::new();
+
+        for (coord, agg) in progress.into_iter() {
+            for next in map.heights.neighbors(coord) {
+                if map.heights[next] == i {
+                    next_progress
+                        .entry(next)
+                        .and_modify(|e| {
+                            e.extend(&agg);
+                        })
+                        .or_insert_with(|| agg.clone());
+                }
+            }
+        }
+
+        progress = next_progress;
+    }
+
+    progress
+}
+
+#[derive(Debug)]
+pub struct Map {
+    heights: Grid<u8>,
+}
+
+#[derive(Debug, Clone)]
+struct TrailAgg {
+    perm: u64,
+    zeros: BTreeSet<usize>,
+}
+
+impl TrailAgg {
+    fn new(seed: usize) -> Self {
+        TrailAgg {
+            perm: 1,
+            zeros: BTreeSet::from([seed]),
+        }
+    }
+
+    fn extend(&mut self, other: &TrailAgg) {
+        self.perm += other.perm;
+        self.zeros.extend(&other.zeros);
+    }
+}
+
+/// Random heights, which hardly ever climb by one at a time, with trails walked over them from 0 up to 9.
+fn generate(rng: &mut Rng, side: usize) -> String {
+    let side = side.max(1);
+    let mut map = Grid::from_cells(side, (0..side * side).map(|_| rng.below(10) as u8).collect());
+    for _ in 0..(side * side / 20).max(1) {
+        let mut at = rng.below(map.len());
+        map[at] = 0;
+        for height in 1..=9 {
+            let next: Vec<usize> = map.neighbors(at).collect();
+            at = *rng.pick(&next);
+            map[at] = height;
+        }
+    }
+    map.to_string()
+}
@@ -0,0 +1,153 @@
+use crate::advent::{column_of, parse_num, Error, Log, Param, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::validate::Grammar;
+use hashbrown::HashMap;
+use itertools::Itertools;
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input = Stones;
+
+    fn parse(&self, input: &str, params: &Params) -> Result<Self::Input> {
+        let stones = input
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.split_whitespace()
+                    .map(move |s| parse_num(s).map_err(|e| e.at(y, column_of(line, s))))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Stones {
+            stones,
+            blinks_1: blinks(params, "blinks_1")?,
+            blinks_2: blinks(params, "blinks_2")?,
+        })
+    }
+
+    fn part_1(&self, input: &mut Self::Input, log: &Log) -> Result<String> {
+        let mut lookup = HashMap::with_capacity(140_000);
+        Ok(count_stones(&input.stones, input.blinks_1, &mut lookup, log)?.to_string())
+    }
+
+    fn part_2(&self, input: &mut Self::Input, log: &Log) -> Result<String> {
+        let mut lookup = HashMap::with_capacity(140_000);
+        Ok(count_stones(&input.stones, input.blinks_2, &mut lookup, log)?.to_string())
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("188902", "223894720281135")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("55312"), Some("65601038650482"))
+    }
+
+    fn name(&self) -> &'static str {
+        "Plutonian Pebbles"
+    }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Lines("{n ...}")
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "stones",
+            default_size: 8,
+            generate,
+        })
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[
+            Param {
+                name: "blinks_1",
+                sample: 25,
+                real: 25,
+            },
+            Param {
+                name: "blinks_2",
+                sample: 75,
+                real: 75,
+            },
+        ]
+    }
+}
+
+pub struct Stones {
+    stones: Vec<u64>,
+    blinks_1: u8,
+    blinks_2: u8,
+}
+
+fn blinks(params: &Params, name: &str) -> Result<u8> {
+    u8::try_from(params.get(name)).map_err(|_| Error::unsolvable(format!("can't blink more than {} times", u8::MAX)))
+}
+
+fn count_stones(stones: &[u64], max_gens: u8, lookup: &mut HashMap<(u64, u8), u64>, log: &Log) -> Result<u64> {
+    let mut total = 0;
+    for stone in stones {
+        log.step()?;
+        total += count_stone(*stone, max_gens, lookup);
+    }
+    Ok(total)
+}
+
+fn count_stone(stone: u64, gens_left: u8, lookup: &mut HashMap<(u64, u8), u64>) -> u64 {
+    if gens_left == 0 {
+        return 1;
+    }
+
+    let key = (stone, gens_left);
+
+    if let Some(count) = lookup.get(&key) {
+        return *count;
+    }
+
+    let (first, second) = next_stone(stone);
+    let next_gen = gens_left - 1;
+
+    let mut count = count_stone(first, next_gen, lookup);
+    if let Some(second) = second {
+        count += count_stone(second, next_gen, lookup);
+    }
+
+    lookup.insert(key, count);
+
+    count
+}
+
+fn next_stone(stone: u64) -> (u64, Option<u64>) {
+    if stone == 0 {
+        return (1, None);
+    }
+
+    if let Some((first, second)) = split_in_two(stone) {
+        return (first, Some(second));
+    }
+
+    (stone * 2024, None)
+}
+
+fn split_in_two(num: u64) -> Option<(u64, u64)> {
+    let num_digits = ((num as f64).log10() + 1.0) as u32;
+
+    if !num_digits.is_multiple_of(2) {
+        return None;
+    }
+
+    let split_position = num_digits / 2;
+
+    let divisor = 10u64.pow(split_position);
+
+    let high = num / divisor;
+    let low = num % divisor;
+
+    Some((high, low))
+}
+
+fn generate(rng: &mut Rng, stones: usize) -> String {
+    format!("{}\n", (0..stones).map(|_| rng.between(0, 9_999_999)).join(" "))
+}
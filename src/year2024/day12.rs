@@ -0,0 +1,145 @@
+use crate::advent::{Log, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::grid::Grid;
+use crate::validate::{Cells, Grammar};
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
+        Grid::parse(input, |c, _, _| Ok(c))
+    }
+
+    fn part_1(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        Ok(solve(input, false).to_string())
+    }
+
+    fn part_2(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        Ok(solve(input, true).to_string())
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("1319878", "784982")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("1930"), Some("1206"))
+    }
+
+    fn name(&self) -> &'static str {
+        "Garden Groups"
+    }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Grid(Cells {
+            allowed: "A-Z",
+            exactly: &[],
+            size: None,
+        })
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "rows and columns",
+            default_size: 140,
+            generate,
+        })
+    }
+}
+
+fn solve(map: &Grid<char>, count_sides: bool) -> u64 {
+    let mut visited: Vec<bool> = vec![false; map.len()];
+    let mut search_stack: Vec<usize> = Vec::new();
+    let mut total_with_perim = 0;
+    let mut total_with_sides = 0;
+
+    for i in 0..map.len() {
+        if visited[i] {
+            continue;
+        }
+
+        let mut area = 0;
+        let mut perim = 0;
+        let mut sides = 0;
+
+        let c = map[i];
+        search_stack.push(i);
+
+        while let Some(j) = search_stack.pop() {
+            let visit_status = visited.get_mut(j).unwrap();
+            if *visit_status {
+                continue;
+            }
+            *visit_status = true;
+
+            area += 1;
+
+            // the neighbour dx across and dy down, if it's part of the same region
+            let same = |dx: isize, dy: isize| map.offset(j, dx, dy).filter(|&k| map[k] == c);
+            let (up, down, left, right) = (same(0, -1), same(0, 1), same(-1, 0), same(1, 0));
+
+            if count_sides {
+                // convex corners
+                sides += [(up, right), (up, left), (down, left), (down, right)]
+                    .iter()
+                    .filter(|&(no_1, no_2)| no_1.is_none() && no_2.is_none())
+                    .count();
+
+                // concave corners
+                sides += [
+                    (left, up, (-1, -1)),
+                    (right, up, (1, -1)),
+                    (left, down, (-1, 1)),
+                    (right, down, (1, 1)),
+                ]
+                .iter()
+                .filter(|(yes_1, yes_2, no)| yes_1.is_some() && yes_2.is_some() && same(no.0, no.1).is_none())
+                .count();
+            }
+
+            // directions to explore
+            for neighbor in [up, down, left, right] {
+                if let Some(next) = neighbor {
+                    search_stack.push(next)
+                } else if !count_sides {
+                    perim += 1
+                };
+            }
+        }
+
+        if count_sides {
+            total_with_sides += area * sides;
+        } else {
+            total_with_perim += area * perim;
+        }
+    }
+
+    if count_sides {
+        total_with_sides as u64
+    } else {
+        total_with_perim as u64
+    }
+}
+
+/// Regions grown outwards from random seeds a square at a time, picking from the edge at random so they come out
+/// ragged, with holes and neighbours that happen to share a letter.
+fn generate(rng: &mut Rng, side: usize) -> String {
+    let side = side.max(1);
+    let mut garden = Grid::new(side, side, None);
+    let mut edge = Vec::new();
+    for _ in 0..(side * side / 40).max(1) {
+        edge.push((rng.below(garden.len()), (b'A' + rng.below(26) as u8) as char));
+    }
+
+    while !edge.is_empty() {
+        let (i, plant) = edge.swap_remove(rng.below(edge.len()));
+        if garden[i].is_none() {
+            garden[i] = Some(plant);
+            edge.extend(garden.neighbors(i).filter(|&n| garden[n].is_none()).map(|n| (n, plant)));
+        }
+    }
+
+    garden.map(|plant| plant.unwrap()).to_string()
+}
@@ -0,0 +1,159 @@
+use crate::advent::{column_of, parse_num, Error, Log, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::validate::Grammar;
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input = Vec<Machine>;
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
+        let mut machines = Vec::new();
+        let mut lines = input.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+
+        while let Some(button_a_line) = lines.next() {
+            let missing = |what: &str| Error::parse(format!("expected a {} line", what)).at_line(button_a_line.0 + 1);
+            let button_b_line = lines.next().ok_or_else(|| missing("Button B"))?;
+            let prize_line = lines.next().ok_or_else(|| missing("Prize"))?;
+
+            let button_a = parse_coordinates(button_a_line, "Button A: ", "X+", " Y+")?;
+            let button_b = parse_coordinates(button_b_line, "Button B: ", "X+", " Y+")?;
+            let (prize_x, prize_y) = parse_coordinates(prize_line, "Prize: ", "X=", " Y=")?;
+
+            machines.push(Machine {
+                button_a: Button {
+                    x: button_a.0,
+                    y: button_a.1,
+                },
+                button_b: Button {
+                    x: button_b.0,
+                    y: button_b.1,
+                },
+                prize_x,
+                prize_y,
+            });
+        }
+
+        Ok(machines)
+    }
+
+    fn part_1(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        Ok(solve(input, |prize| prize, Some(100))?.to_string())
+    }
+
+    fn part_2(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        Ok(solve(input, |prize| prize + 10000000000000, None)?.to_string())
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("29023", "96787395375634")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("480"), Some("875318608908"))
+    }
+
+    fn name(&self) -> &'static str {
+        "Claw Contraption"
+    }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Repeated(&Grammar::Block(&[
+            "Button A: X+{n}, Y+{n}",
+            "Button B: X+{n}, Y+{n}",
+            "Prize: X={n}, Y={n}",
+        ]))
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "machines",
+            default_size: 320,
+            generate,
+        })
+    }
+}
+
+/// Tokens for every prize that can be won, pressing each button no more than `max_presses` times if there's a limit.
+fn solve<F: Fn(i64) -> i64>(parsed: &[Machine], f: F, max_presses: Option<i64>) -> Result<u64> {
+    let mut a_total = 0;
+    let mut b_total = 0;
+
+    for machine in parsed {
+        let prize_x = f(machine.prize_x);
+        let prize_y = f(machine.prize_y);
+
+        let denom = (machine.button_a.y * machine.button_b.x) - (machine.button_a.x * machine.button_b.y);
+
+        if denom == 0 {
+            return Err(Error::unsolvable("we only handle linearly independent buttons"));
+        }
+
+        let a_num = (prize_y * machine.button_b.x) - (prize_x * machine.button_b.y);
+        let b_num = (prize_x * machine.button_a.y) - (prize_y * machine.button_a.x);
+
+        // buttons can't be pressed a fraction of a time, or a negative number of times
+        if a_num % denom != 0 || b_num % denom != 0 {
+            continue;
+        }
+        let (a_count, b_count) = (a_num / denom, b_num / denom);
+        if a_count < 0 || b_count < 0 || max_presses.is_some_and(|max| a_count > max || b_count > max) {
+            continue;
+        }
+
+        a_total += a_count;
+        b_total += b_count;
+    }
+
+    Ok((a_total * 3 + b_total) as u64)
+}
+
+fn parse_coordinates((y, line): (usize, &str), prefix: &str, x_prefix: &str, y_prefix: &str) -> Result<(i64, i64)> {
+    let expected = |what: &str, at: &str| Error::parse(format!("expected {:?}", what)).at(y, column_of(line, at));
+
+    let rest = line.strip_prefix(prefix).ok_or_else(|| expected(prefix, line))?;
+    let (x_part, y_part) = rest.split_once(',').ok_or_else(|| expected(",", rest))?;
+    let x_num = x_part.strip_prefix(x_prefix).ok_or_else(|| expected(x_prefix, x_part))?;
+    let y_num = y_part.strip_prefix(y_prefix).ok_or_else(|| expected(y_prefix, y_part))?;
+
+    let num = |s: &str| parse_num(s).map_err(|e| e.at(y, column_of(line, s)));
+    Ok((num(x_num)?, num(y_num)?))
+}
+
+#[derive(Debug)]
+pub struct Machine {
+    button_a: Button,
+    button_b: Button,
+    prize_x: i64,
+    prize_y: i64,
+}
+
+#[derive(Debug)]
+pub struct Button {
+    x: i64,
+    y: i64,
+}
+
+/// Machines with buttons that don't point the same way, about half of them with a prize the buttons can reach.
+fn generate(rng: &mut Rng, machines: usize) -> String {
+    let mut out = Vec::new();
+    while out.len() < machines {
+        let (ax, ay, bx, by) = (rng.between(10, 99), rng.between(10, 99), rng.between(10, 99), rng.between(10, 99));
+        if ax * by == ay * bx {
+            continue;
+        }
+
+        let (px, py) = if rng.chance(0.5) {
+            let (a, b) = (rng.between(1, 100), rng.between(1, 100));
+            (a * ax + b * bx, a * ay + b * by)
+        } else {
+            (rng.between(1000, 20000), rng.between(1000, 20000))
+        };
+
+        out.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            ax, ay, bx, by, px, py
+        ));
+    }
+    out.join("\n")
+}
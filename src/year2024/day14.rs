@@ -0,0 +1,232 @@
+use crate::advent::{parse_num, Error, Log, Param, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::grid::{self, Grid};
+use crate::render::{self, Render, Rgb};
+use crate::validate::Grammar;
+use regex::Regex;
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Input = Floor;
+
+    fn parse(&self, input: &str, params: &Params) -> Result<Self::Input> {
+        let regex = Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)").unwrap();
+        let robots = input
+            .lines()
+            .enumerate()
+            .map(|(y, l)| {
+                let caps = regex
+                    .captures(l)
+                    .ok_or_else(|| Error::parse("expected \"p=<x>,<y> v=<dx>,<dy>\"").at_line(y))?;
+                let num = |i: usize| parse_num(&caps[i]).map_err(|e| e.at(y, caps.get(i).unwrap().start()));
+                Ok(Robot {
+                    pos: (num(1)?, num(2)?),
+                    velocity: (num(3)?, num(4)?),
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        let (width, height) = (side(params, "width")?, side(params, "height")?);
+        // which also keeps part 2's count of seconds, the area, well inside an i32
+        grid::area(width as usize, height as usize)?;
+
+        Ok(Floor { width, height, robots })
+    }
+
+    fn part_1(&self, floor: &mut Self::Input, _log: &Log) -> Result<String> {
+        let mut quadrants: [u64; 4] = [0, 0, 0, 0];
+
+        for robot in &floor.robots {
+            let (x, y) = tick_robot(robot, 100, floor.width, floor.height);
+
+            if x == floor.width / 2 || y == floor.height / 2 {
+                continue;
+            }
+
+            let is_left = x < floor.width / 2;
+            let is_top = y < floor.height / 2;
+            let quadrant = (is_top as usize) * 2 + is_left as usize;
+
+            quadrants[quadrant] += 1;
+        }
+
+        Ok(quadrants.iter().product::<u64>().to_string())
+    }
+
+    fn part_2(&self, floor: &mut Self::Input, log: &Log) -> Result<String> {
+        let mut visited = Grid::new(floor.width as usize, floor.height as usize, 0);
+
+        for i in 1..=floor.width * floor.height {
+            log.step()?;
+            let mut found_dupe = false;
+            for robot in floor.robots.iter_mut() {
+                let (x, y) = robot.tick(1, floor.width, floor.height);
+
+                if !found_dupe {
+                    let at = visited.index_of(x as usize, y as usize);
+                    let j = &mut visited[at];
+                    if *j == i {
+                        found_dupe = true;
+                    }
+                    *j = i;
+                }
+            }
+
+            if !found_dupe && hard_check(&visited, i) {
+                log.render("tree", floor)?;
+                return Ok(i.to_string());
+            }
+        }
+        Ok("0".to_string())
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("214109808", "7687")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("12"), None)
+    }
+
+    fn name(&self) -> &'static str {
+        "Restroom Rebound"
+    }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Lines("p={n},{n} v={i},{i}")
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "robots",
+            default_size: 500,
+            generate,
+        })
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[
+            Param {
+                name: "width",
+                sample: 11,
+                real: 101,
+            },
+            Param {
+                name: "height",
+                sample: 7,
+                real: 103,
+            },
+        ]
+    }
+}
+
+/// Whether some row has a long unbroken run of robots that all got there at the second being checked.
+fn hard_check(counts: &Grid<i32>, expected: i32) -> bool {
+    counts
+        .rows()
+        .any(|row| row.windows(12).any(|window| window.iter().all(|&b| b == expected)))
+}
+
+/// A side of the floor, which the robots wrap around by taking a remainder, so it can't be zero.
+fn side(params: &Params, name: &str) -> Result<i32> {
+    match i32::try_from(params.get(name)) {
+        Ok(0) => Err(Error::parse(format!("the floor's {} can't be 0", name))),
+        Ok(n) => Ok(n),
+        Err(_) => Err(Error::parse(format!("the floor's {} of {} is too big", name, params.get(name)))),
+    }
+}
+
+fn tick_robot(robot: &Robot, seconds: i32, width: i32, height: i32) -> (i32, i32) {
+    let mut x = (robot.pos.0 + robot.velocity.0 * seconds) % width;
+    let mut y = (robot.pos.1 + robot.velocity.1 * seconds) % height;
+
+    if x < 0 {
+        x += width;
+    }
+
+    if y < 0 {
+        y += height;
+    }
+
+    (x, y)
+}
+
+#[derive(Debug)]
+struct Robot {
+    pos: (i32, i32),
+    velocity: (i32, i32),
+}
+
+impl Robot {
+    fn tick(&mut self, seconds: i32, width: i32, height: i32) -> (i32, i32) {
+        let (x, y) = tick_robot(self, seconds, width, height);
+        self.pos = (x, y);
+        (x, y)
+    }
+}
+
+#[derive(Debug)]
+pub struct Floor {
+    width: i32,
+    height: i32,
+    robots: Vec<Robot>,
+}
+
+impl Render for Floor {
+    fn size(&self) -> (usize, usize) {
+        (self.width as usize, self.height as usize)
+    }
+
+    fn cell(&self, x: usize, y: usize) -> (char, Rgb) {
+        // only drawn once, so going through every robot for every cell is neither here nor there
+        let count = self.robots.iter().filter(|r| r.pos == (x as i32, y as i32)).count();
+        match count {
+            0 => ('.', render::BLACK),
+            1..=9 => (char::from_digit(count as u32, 10).unwrap(), render::GREEN),
+            _ => ('+', render::GREEN),
+        }
+    }
+}
+
+/// Robots wandering the real 101x103 floor, which at some random second all stand on different squares, framing a
+/// Christmas tree if there are enough of them to draw one.
+fn generate(rng: &mut Rng, robots: usize) -> String {
+    const WIDTH: i64 = 101;
+    const HEIGHT: i64 = 103;
+
+    // a 31x33 frame round a triangle 15 rows tall
+    let mut tree = Vec::new();
+    for y in 0..33i64 {
+        for x in 0..31i64 {
+            let in_frame = x == 0 || x == 30 || y == 0 || y == 32;
+            let in_tree = (2..17).contains(&y) && (x - 15).abs() <= y - 2;
+            if in_frame || in_tree {
+                tree.push((x, y));
+            }
+        }
+    }
+
+    let (left, top) = (rng.between(0, WIDTH - 31), rng.between(0, HEIGHT - 33));
+    let mut squares: Vec<(i64, i64)> = if robots >= tree.len() {
+        tree.iter().map(|(x, y)| (left + x, top + y)).collect()
+    } else {
+        Vec::new()
+    };
+    let mut rest: Vec<(i64, i64)> = (0..HEIGHT)
+        .flat_map(|y| (0..WIDTH).map(move |x| (x, y)))
+        .filter(|square| !squares.contains(square))
+        .collect();
+    rng.shuffle(&mut rest);
+    squares.extend(rest.into_iter().take(robots.saturating_sub(squares.len())));
+
+    // working back from where each is at that second to where it starts
+    let second = rng.between(1, WIDTH * HEIGHT - 1);
+    let mut out = String::new();
+    for (x, y) in squares {
+        let (dx, dy) = (rng.between(-100, 100), rng.between(-100, 100));
+        let (px, py) = ((x - dx * second).rem_euclid(WIDTH), (y - dy * second).rem_euclid(HEIGHT));
+        out.push_str(&format!("p={},{} v={},{}\n", px, py, dx, dy));
+    }
+    out
+}
@@ -0,0 +1,285 @@
+use crate::advent::{Error, Log, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::grid::{Dir, Grid};
+use crate::render::{self, Render, Rgb};
+use crate::validate::{Cells, Grammar};
+use std::collections::BTreeSet;
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    type Input = (Map, Vec<char>);
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
+        let (map_txt, dir_txt) = input
+            .split_once("\n\n")
+            .ok_or_else(|| Error::parse("expected a blank line between the map and the moves"))?;
+
+        let mut robot_location: Option<(usize, usize)> = None;
+        let squares = Grid::parse(map_txt, |c, x, y| match c {
+            '@' => {
+                if robot_location.is_some() {
+                    return Err(Error::parse("found a second robot"));
+                }
+                robot_location = Some((x, y));
+                Ok(Square::Empty)
+            }
+            '.' => Ok(Square::Empty),
+            'O' => Ok(Square::Box),
+            '#' => Ok(Square::Wall),
+            _ => Err(Error::parse(format!("unexpected {:?}", c))),
+        })?;
+
+        let first_dir_line = map_txt.lines().count() + 1;
+        let mut dirs: Vec<char> = Vec::with_capacity(dir_txt.len());
+        for (y, line) in dir_txt.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '<' | '>' | '^' | 'v' => dirs.push(c),
+                    _ => return Err(Error::parse(format!("expected a move, found {:?}", c)).at(first_dir_line + y, x)),
+                }
+            }
+        }
+
+        let (x, y) = robot_location.ok_or_else(|| Error::parse("no robot found"))?;
+
+        Ok((
+            Map {
+                robot_location: squares.index_of(x, y),
+                squares,
+            },
+            dirs,
+        ))
+    }
+
+    fn part_1(&self, (map, dirs): &mut Self::Input, log: &Log) -> Result<String> {
+        let mut map = map.clone();
+        Ok(solve(&mut map, dirs, log)?.to_string())
+    }
+
+    fn part_2(&self, (smol_map, dirs): &mut Self::Input, log: &Log) -> Result<String> {
+        let mut map = embiggen_squares(smol_map);
+        Ok(solve(&mut map, dirs, log)?.to_string())
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("1442192", "1448458")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("10092"), Some("9021"))
+    }
+
+    fn name(&self) -> &'static str {
+        "Warehouse Woes"
+    }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Sections(&[
+            Grammar::Grid(Cells {
+                allowed: "#.O@",
+                exactly: &[("@", 1)],
+                size: None,
+            }),
+            Grammar::Lines("{[<>^v]}"),
+        ])
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "rows and columns",
+            default_size: 50,
+            generate,
+        })
+    }
+}
+
+fn solve(map: &mut Map, dirs: &[char], log: &Log) -> Result<u64> {
+    let mut train: Vec<(usize, Square)> = Vec::new();
+
+    'dir: for (i, dir) in dirs.iter().enumerate() {
+        let dir = Dir::from_char(*dir).unwrap();
+        // the map after `i` moves, drawn up here because a move into a wall skips the rest of the loop
+        if log.rendering() {
+            log.render(&format!("move{:05}", i), map)?;
+        }
+        train.clear();
+
+        let is_vert = matches!(dir, Dir::Up | Dir::Down);
+
+        let mut lanes: BTreeSet<usize> = BTreeSet::from([map.robot_location]);
+        let mut next_robot_location = 0;
+
+        loop {
+            let mut end_of_the_line = true;
+            let mut new_lanes: BTreeSet<usize> = BTreeSet::new();
+            for lane in lanes.into_iter() {
+                let next_idx = step(map, lane, dir)?;
+                let next = &map.squares[next_idx];
+
+                if next_robot_location == 0 {
+                    next_robot_location = next_idx;
+                }
+
+                match next {
+                    Square::Empty => {}
+
+                    Square::BoxLeft => {
+                        end_of_the_line = false;
+                        train.push((next_idx, Square::BoxLeft));
+                        new_lanes.insert(next_idx);
+
+                        if is_vert {
+                            new_lanes.insert(next_idx + 1);
+                            train.push((next_idx + 1, Square::BoxRight));
+                        }
+                    }
+
+                    Square::BoxRight => {
+                        end_of_the_line = false;
+                        train.push((next_idx, Square::BoxRight));
+                        new_lanes.insert(next_idx);
+
+                        if is_vert {
+                            new_lanes.insert(next_idx - 1);
+                            train.push((next_idx - 1, Square::BoxLeft));
+                        }
+                    }
+
+                    Square::Box => {
+                        end_of_the_line = false;
+                        new_lanes.insert(next_idx);
+                        train.push((next_idx, Square::Box));
+                    }
+                    Square::Wall => {
+                        continue 'dir;
+                    }
+                }
+            }
+
+            if end_of_the_line {
+                map.robot_location = next_robot_location;
+                for (idx, square) in train.iter().rev() {
+                    let old = map.squares.get_mut(*idx).unwrap();
+                    *old = Square::Empty;
+
+                    let new_idx = step(map, *idx, dir)?;
+                    let new = map.squares.get_mut(new_idx).unwrap();
+                    *new = *square;
+                }
+                break;
+            }
+
+            lanes = new_lanes;
+        }
+    }
+    if log.rendering() {
+        log.render(&format!("move{:05}", dirs.len()), map)?;
+    }
+    Ok(map.sum_coords() as u64)
+}
+
+/// A walled-in warehouse never lets anything that moves get to the edge, but one that isn't does.
+fn step(map: &Map, idx: usize, dir: Dir) -> Result<usize> {
+    map.squares
+        .step(idx, dir)
+        .ok_or_else(|| Error::unsolvable("walked off the edge of the warehouse, which isn't walled in"))
+}
+
+fn embiggen_squares(map: &Map) -> Map {
+    let mut squares = Vec::with_capacity(map.squares.len() * 2);
+    for square in map.squares.iter() {
+        match square {
+            Square::Box => {
+                squares.push(Square::BoxLeft);
+                squares.push(Square::BoxRight);
+            }
+
+            _ => {
+                squares.push(*square);
+                squares.push(*square);
+            }
+        }
+    }
+
+    Map {
+        robot_location: map.robot_location * 2,
+        squares: Grid::from_cells(map.squares.width() * 2, squares),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Square {
+    BoxLeft,
+    BoxRight,
+    Box,
+    Empty,
+    Wall,
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    robot_location: usize,
+    squares: Grid<Square>,
+}
+
+impl Map {
+    fn sum_coords(&self) -> usize {
+        self.squares
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| matches!(s, Square::Box) || matches!(s, Square::BoxLeft))
+            .map(|(i, _)| {
+                let (x, y) = self.squares.xy(i);
+                x + y * 100
+            })
+            .sum()
+    }
+}
+
+impl Render for Map {
+    fn size(&self) -> (usize, usize) {
+        (self.squares.width(), self.squares.height())
+    }
+
+    fn cell(&self, x: usize, y: usize) -> (char, Rgb) {
+        let i = self.squares.index_of(x, y);
+        if i == self.robot_location {
+            return ('@', render::RED);
+        }
+        match self.squares[i] {
+            Square::Box => ('O', render::YELLOW),
+            Square::BoxLeft => ('[', render::YELLOW),
+            Square::BoxRight => (']', render::YELLOW),
+            Square::Empty => ('.', render::BLACK),
+            Square::Wall => ('#', render::GREY),
+        }
+    }
+}
+
+/// A walled warehouse about a quarter full of boxes, then eight moves for every square in it, a thousand to a line.
+fn generate(rng: &mut Rng, side: usize) -> String {
+    let side = side.max(3);
+    let mut warehouse = Grid::new(side, side, '#');
+    for i in 0..warehouse.len() {
+        let (x, y) = warehouse.xy(i);
+        if x > 0 && y > 0 && x < side - 1 && y < side - 1 {
+            warehouse[i] = match rng.below(100) {
+                0..8 => '#',
+                8..34 => 'O',
+                _ => '.',
+            };
+        }
+    }
+    let robot = warehouse.index_of(rng.between(1, side as i64 - 2) as usize, rng.between(1, side as i64 - 2) as usize);
+    warehouse[robot] = '@';
+
+    let moves: Vec<char> = (0..side * side * 8).map(|_| *rng.pick(&['<', '>', '^', 'v'])).collect();
+    let mut out = warehouse.to_string();
+    out.push('\n');
+    for line in moves.chunks(1000) {
+        out.extend(line);
+        out.push('\n');
+    }
+    out
+}
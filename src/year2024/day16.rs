@@ -0,0 +1,164 @@
+use crate::advent::{Error, Log, Params, Result, Solver};
+use crate::generate::{self, Generator, Rng};
+use crate::grid::{Dir, Grid};
+use crate::render::{self, Render, Rgb};
+use crate::search;
+use crate::validate::{Cells, Grammar};
+use hashbrown::HashSet;
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    type Input = Maze;
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
+        let mut start = None;
+        let mut end = None;
+
+        let map = Grid::parse(input, |c, x, y| {
+            match c {
+                'S' => start = Some((x, y)),
+                'E' => end = Some((x, y)),
+                '.' | '#' => {}
+                _ => return Err(Error::parse(format!("unexpected {:?}", c))),
+            }
+            Ok(c)
+        })?;
+
+        let (start_x, start_y) = start.ok_or_else(|| Error::parse("no start found"))?;
+        let (end_x, end_y) = end.ok_or_else(|| Error::parse("no end found"))?;
+        Ok(Maze {
+            start: map.index_of(start_x, start_y),
+            end: map.index_of(end_x, end_y),
+            map,
+        })
+    }
+
+    fn part_1(&self, maze: &mut Self::Input, log: &Log) -> Result<String> {
+        // no way to the end can be shorter than the straight line to it, turns aside
+        let (end_x, end_y) = maze.map.xy(maze.end);
+        let distance = |&(coord, _): &Reindeer| {
+            let (x, y) = maze.map.xy(coord);
+            (x.abs_diff(end_x) + y.abs_diff(end_y)) as u64
+        };
+
+        let path = search::astar((maze.start, Dir::Right), |r| maze.moves(r), distance, |r| r.0 == maze.end)
+            .ok_or_else(|| Error::unsolvable("there's no path from S to E"))?;
+        log.debug(format_args!(
+            "lowest score {} after exploring {} states",
+            path.cost,
+            path.explored()
+        ));
+        Ok(path.cost.to_string())
+    }
+
+    fn part_2(&self, maze: &mut Self::Input, log: &Log) -> Result<String> {
+        let paths = search::dijkstra_all((maze.start, Dir::Right), |r| maze.moves(r), |r| r.0 == maze.end)
+            .ok_or_else(|| Error::unsolvable("there's no path from S to E"))?;
+        let tiles: HashSet<usize> = paths.states().iter().map(|(coord, _)| *coord).collect();
+
+        log.debug(format_args!("{} tiles are on a best path", tiles.len()));
+        let best = BestPaths { maze, marked: &tiles };
+        if log.enabled(2) {
+            log.trace(format_args!("best paths:\n{}", best.ascii()));
+        }
+        log.render("best-paths", &best)?;
+
+        Ok(tiles.len().to_string())
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("85432", "465")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("11048"), Some("64"))
+    }
+
+    fn name(&self) -> &'static str {
+        "Reindeer Maze"
+    }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Grid(Cells {
+            allowed: "#.SE",
+            exactly: &[("S", 1), ("E", 1)],
+            size: None,
+        })
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "rows and columns",
+            default_size: 141,
+            generate,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Maze {
+    start: usize,
+    end: usize,
+    map: Grid<char>,
+}
+
+/// Where the reindeer is and which way it's facing.
+type Reindeer = (usize, Dir);
+
+impl Maze {
+    /// On ahead for 1 point, or a turn either way and a step for 1001, as long as there's no wall in the way.
+    fn moves(&self, &(coord, dir): &Reindeer) -> impl Iterator<Item = (Reindeer, u64)> + '_ {
+        [(dir, 1), (dir.rotate_left(), 1001), (dir.rotate_right(), 1001)]
+            .into_iter()
+            .filter_map(move |(dir, cost)| {
+                let next = self.map.step(coord, dir)?;
+                (self.map[next] != '#').then_some(((next, dir), cost))
+            })
+    }
+}
+
+/// The maze with the tiles on any of the best paths drawn as `O`, the way the puzzle shows them.
+struct BestPaths<'a> {
+    maze: &'a Maze,
+    marked: &'a HashSet<usize>,
+}
+
+impl Render for BestPaths<'_> {
+    fn size(&self) -> (usize, usize) {
+        (self.maze.map.width(), self.maze.map.height())
+    }
+
+    fn cell(&self, x: usize, y: usize) -> (char, Rgb) {
+        let i = self.maze.map.index_of(x, y);
+        match self.maze.map[i] {
+            _ if self.marked.contains(&i) => ('O', render::YELLOW),
+            '#' => ('#', render::GREY),
+            c => (c, render::BLACK),
+        }
+    }
+}
+
+/// A maze with one in ten of its inside walls knocked through, so there are loops and more than one best path, from
+/// the bottom left corner to the top right like the real one.
+fn generate(rng: &mut Rng, side: usize) -> String {
+    let open = generate::maze(rng, side, side);
+    let (width, height) = (open.width(), open.height());
+
+    let mut maze = open.map(|&open| if open { '.' } else { '#' });
+    for i in 0..maze.len() {
+        let (x, y) = maze.xy(i);
+        let between = |a: Option<usize>, b: Option<usize>| a.zip(b).is_some_and(|(a, b)| open[a] && open[b]);
+        let inside = x > 0 && y > 0 && x < width - 1 && y < height - 1;
+        let joins = between(maze.offset(i, -1, 0), maze.offset(i, 1, 0)) || between(maze.offset(i, 0, -1), maze.offset(i, 0, 1));
+        if !open[i] && inside && joins && rng.chance(0.1) {
+            maze[i] = '.';
+        }
+    }
+
+    let start = maze.index_of(1, height - 2);
+    let end = maze.index_of(width - 2, 1);
+    maze[start] = 'S';
+    maze[end] = 'E';
+    maze.to_string()
+}
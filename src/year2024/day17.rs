@@ -0,0 +1,282 @@
+use crate::advent::{column_of, parse_num, Error, Input, Log, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::validate::Grammar;
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::ops::Shl;
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    type Input = Computer;
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
+        let (reg_text, ins_txt) = input
+            .split_once("\n\n")
+            .ok_or_else(|| Error::parse("expected a blank line between the registers and the program"))?;
+
+        let mut reg_split = reg_text.lines().enumerate();
+        let mut register = |name: &str| -> Result<u64> {
+            let prefix = format!("Register {}: ", name);
+            let (y, line) = reg_split.next().ok_or_else(|| Error::parse(format!("expected {:?}", prefix)))?;
+            let value = line
+                .strip_prefix(&prefix)
+                .ok_or_else(|| Error::parse(format!("expected {:?}", prefix)).at_line(y))?;
+            parse_num(value).map_err(|e| e.at(y, prefix.len()))
+        };
+        let reg_a = register("A")?;
+        let reg_b = register("B")?;
+        let reg_c = register("C")?;
+
+        let program_y = reg_text.lines().count() + 1;
+        let program_line = ins_txt.lines().next().unwrap_or_default();
+        let program = program_line
+            .strip_prefix("Program: ")
+            .ok_or_else(|| Error::parse("expected \"Program: \"").at_line(program_y))?;
+
+        let codes: Vec<(usize, u8)> = program
+            .split(',')
+            .map(|s| {
+                let x = column_of(program_line, s);
+                parse_num(s).map(|code| (x, code)).map_err(|e| e.at(program_y, x))
+            })
+            .collect::<Result<_>>()?;
+
+        if !codes.len().is_multiple_of(2) {
+            return Err(Error::parse("expected an operand after the last opcode").at(program_y, program_line.len()));
+        }
+
+        let mut instructions = vec![];
+        let mut instructions_raw = vec![];
+        for pair in codes.chunks(2) {
+            let [(x, op_code), (_, arg)] = [pair[0], pair[1]];
+            instructions_raw.push(op_code as u64);
+            instructions_raw.push(arg as u64);
+            instructions.push(Instruction::parse(op_code, arg).map_err(|e| e.at(program_y, x))?);
+        }
+
+        Ok(Computer {
+            reg_a,
+            reg_b,
+            reg_c,
+            instructions,
+            instructions_raw,
+            instruction_pointer: 0,
+        })
+    }
+
+    fn part_1(&self, computer: &mut Self::Input, log: &Log) -> Result<String> {
+        let mut output: Vec<u64> = vec![];
+        while let Some(o) = computer.run_until_output(log)? {
+            output.push(o);
+        }
+        Ok(output.iter().join(","))
+    }
+
+    fn part_2(&self, computer: &mut Self::Input, log: &Log) -> Result<String> {
+        let Some(max_index) = computer.instructions_raw.len().checked_sub(1) else {
+            return Err(Error::unsolvable("the program is empty"));
+        };
+        let mut solutions: BinaryHeap<Sol> = BinaryHeap::from([Sol { a: 0, iteration: 0 }]);
+
+        while let Some(sol) = solutions.pop() {
+            let goal = computer.instructions_raw[max_index - sol.iteration];
+            let val = sol.a.shl(3);
+            for a in val..val + 8 {
+                computer.reset(a);
+                let output = computer.run_until_output(log)?;
+
+                if output == Some(goal) {
+                    if sol.iteration == max_index {
+                        return Ok(a.to_string());
+                    }
+
+                    solutions.push(Sol {
+                        a,
+                        iteration: sol.iteration + 1,
+                    });
+                }
+            }
+        }
+        Err(Error::unsolvable("no value of register A makes the program output itself"))
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("1,5,0,3,7,3,0,3,1", "105981155568026")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("5,7,3,0"), Some("117440"))
+    }
+
+    fn name(&self) -> &'static str {
+        "Chronospacial Computer"
+    }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Sections(&[
+            Grammar::Block(&["Register A: {n}", "Register B: {n}", "Register C: {n}"]),
+            Grammar::Block(&["Program: {d,...}"]),
+        ])
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "bits in register A",
+            default_size: 26,
+            generate,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Sol {
+    a: u64,
+    iteration: usize,
+}
+
+impl PartialOrd<Self> for Sol {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Sol {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.a.cmp(&self.a)
+    }
+}
+
+#[derive(Debug)]
+pub struct Computer {
+    instruction_pointer: usize,
+    reg_a: u64,
+    reg_b: u64,
+    reg_c: u64,
+    instructions: Vec<Instruction>,
+    instructions_raw: Vec<u64>,
+}
+
+impl Computer {
+    fn arg(&self, arg: &Combo) -> u64 {
+        match arg {
+            Combo::Literal(v) => *v as u64,
+            Combo::RegA => self.reg_a,
+            Combo::RegB => self.reg_b,
+            Combo::RegC => self.reg_c,
+        }
+    }
+
+    /// A divided by 2 to the power of the combo operand. A shift of 64 or more leaves nothing, rather than being
+    /// wrapped round to a smaller one as a plain `>>` would in release builds.
+    fn divide(&self, arg: &Combo) -> u64 {
+        u32::try_from(self.arg(arg))
+            .ok()
+            .and_then(|n| self.reg_a.checked_shr(n))
+            .unwrap_or(0)
+    }
+
+    fn reset(&mut self, reg_a: u64) {
+        self.instruction_pointer = 0;
+        self.reg_a = reg_a;
+    }
+
+    /// Runs to the next output, or to the end of the program. Every jump taken counts as a step of `log`'s, since a
+    /// program that never leaves a loop never gets to either.
+    fn run_until_output(&mut self, log: &Log) -> Result<Option<u64>> {
+        while let Some(i) = self.instructions.get(self.instruction_pointer) {
+            match i {
+                Instruction::Bxl(arg) => self.reg_b ^= *arg as u64,
+
+                Instruction::Jnz(arg) => {
+                    if self.reg_a != 0 {
+                        log.step()?;
+                        self.instruction_pointer = (*arg / 2) as usize;
+                        continue;
+                    }
+                }
+
+                Instruction::Bst(combo) => self.reg_b = self.arg(combo) & 0x7,
+                Instruction::Bxc => self.reg_b ^= self.reg_c,
+                Instruction::Out(combo) => {
+                    self.instruction_pointer += 1;
+                    return Ok(Some(self.arg(combo) & 0x7));
+                }
+                Instruction::Adv(combo) => self.reg_a = self.divide(combo),
+                Instruction::Bdv(combo) => self.reg_b = self.divide(combo),
+                Instruction::Cdv(combo) => self.reg_c = self.divide(combo),
+            }
+
+            self.instruction_pointer += 1;
+        }
+
+        Ok(None)
+    }
+}
+
+#[derive(Debug)]
+enum Instruction {
+    Adv(Combo),
+    Bxl(u8),
+    Bst(Combo),
+    Jnz(u8),
+    Bxc,
+    Out(Combo),
+    Bdv(Combo),
+    Cdv(Combo),
+}
+
+impl Instruction {
+    fn parse(op_code: u8, arg: u8) -> Result<Self> {
+        Ok(match op_code {
+            0 => Self::Adv(Combo::from_u8(arg)?),
+            1 => Self::Bxl(arg),
+            2 => Self::Bst(Combo::from_u8(arg)?),
+            3 => Self::Jnz(arg),
+            4 => Self::Bxc,
+            5 => Self::Out(Combo::from_u8(arg)?),
+            6 => Self::Bdv(Combo::from_u8(arg)?),
+            7 => Self::Cdv(Combo::from_u8(arg)?),
+            _ => return Err(Error::parse(format!("unknown opcode {}", op_code))),
+        })
+    }
+}
+
+#[derive(Debug)]
+enum Combo {
+    Literal(u8),
+    RegA,
+    RegB,
+    RegC,
+}
+
+impl Combo {
+    fn from_u8(v: u8) -> Result<Self> {
+        match v {
+            0..=3 => Ok(Self::Literal(v)),
+            4 => Ok(Self::RegA),
+            5 => Ok(Self::RegB),
+            6 => Ok(Self::RegC),
+            _ => Err(Error::parse(format!("unknown combo operand {}", v))),
+        }
+    }
+}
+
+/// A program shaped like the real ones, which shift A down three bits a round and print something worked out from
+/// them, with the constants mixed up until part 2 has an answer. A starts out with `bits` bits.
+fn generate(rng: &mut Rng, bits: usize) -> String {
+    let bits = bits.clamp(1, 63);
+    let a = rng.next_u64() >> (64 - bits) | 1 << (bits - 1);
+
+    loop {
+        let (first, second, ignored) = (rng.below(8), rng.below(8), rng.below(8));
+        let program = format!("2,4,1,{},7,5,1,{},0,3,4,{},5,5,3,0", first, second, ignored);
+        let input = format!("Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n", a, program);
+
+        let mut computer = Day17.parse(&input, &Params::new(&[], Input::Real)).unwrap();
+        if Day17.part_2(&mut computer, &Log::new(17, 0)).is_ok() {
+            return input;
+        }
+    }
+}
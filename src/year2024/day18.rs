@@ -0,0 +1,161 @@
+use crate::advent::{column_of, parse_num, Error, Log, Param, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::grid::{self, Grid};
+use crate::search;
+use crate::validate::Grammar;
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    type Input = Memory;
+
+    fn parse(&self, input: &str, params: &Params) -> Result<Self::Input> {
+        let dimension = params.get("dimension");
+        let how_many = params.get("how_many");
+        if dimension == 0 {
+            return Err(Error::parse("the memory space can't be 0 wide, since it needs a start and an exit"));
+        }
+        grid::area(dimension, dimension)?;
+
+        let drops: Vec<usize> = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let coord = |s: &str| -> Result<usize> {
+                    let n = parse_num(s).map_err(|e| e.at(i, column_of(line, s)))?;
+                    if n >= dimension {
+                        return Err(
+                            Error::parse(format!("{} is outside the {}x{} space", n, dimension, dimension)).at(i, column_of(line, s))
+                        );
+                    }
+                    Ok(n)
+                };
+                let (x, y) = line
+                    .split_once(",")
+                    .ok_or_else(|| Error::parse("expected \"<x>,<y>\"").at_line(i))?;
+                Ok(coord(x)? + coord(y)? * dimension)
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Memory {
+            drops,
+            dimension,
+            how_many,
+        })
+    }
+
+    fn part_1(&self, memory: &mut Self::Input, _log: &Log) -> Result<String> {
+        let mut space = Grid::new(memory.dimension, memory.dimension, false);
+        memory.fill_space(&mut space, memory.how_many);
+        memory
+            .shortest_path(&space)
+            .map(|steps| steps.to_string())
+            .ok_or_else(|| Error::unsolvable("the exit is already cut off"))
+    }
+
+    fn part_2(&self, memory: &mut Self::Input, _log: &Log) -> Result<String> {
+        // the fewest drops that cut the exit off, where one more than there are means they never do
+        let mut low = 0;
+        let mut high = memory.drops.len() + 1;
+        let mut space = Grid::new(memory.dimension, memory.dimension, false);
+
+        while low < high {
+            let mid = (low + high) / 2;
+            memory.fill_space(&mut space, mid);
+            if memory.shortest_path(&space).is_some() {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        if high > memory.drops.len() {
+            return Err(Error::unsolvable("the exit is never cut off"));
+        }
+        let v = high
+            .checked_sub(1)
+            .map(|i| memory.drops[i])
+            .ok_or_else(|| Error::unsolvable("the exit is cut off before anything falls"))?;
+        Ok(format!("{},{}", v % memory.dimension, v / memory.dimension))
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("272", "16,44")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("22"), Some("6,1"))
+    }
+
+    fn name(&self) -> &'static str {
+        "RAM Run"
+    }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Lines("{n},{n}")
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "bytes",
+            default_size: 3450,
+            generate,
+        })
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[
+            Param {
+                name: "dimension",
+                sample: 7,
+                real: 71,
+            },
+            Param {
+                name: "how_many",
+                sample: 12,
+                real: 1024,
+            },
+        ]
+    }
+}
+
+pub struct Memory {
+    drops: Vec<usize>,
+    dimension: usize,
+    how_many: usize,
+}
+
+impl Memory {
+    /// Makes `space` the memory space with the first `drops` bytes fallen, `true` where one has, reusing it rather
+    /// than starting a new one for every probe of the search.
+    fn fill_space(&self, space: &mut Grid<bool>, drops: usize) {
+        space.fill(false);
+        for i in self.drops.iter().take(drops) {
+            space[*i] = true;
+        }
+    }
+
+    /// Steps from the top left corner to the bottom right, if there's a way through.
+    fn shortest_path(&self, space: &Grid<bool>) -> Option<u64> {
+        let exit = space.len() - 1;
+        search::bfs_dense(
+            0,
+            space.len(),
+            |coord| space.neighbors(coord).filter(|&n| !space[n]),
+            |coord| coord == exit,
+        )
+    }
+}
+
+/// Bytes falling on distinct squares of the real 71x71 space, never on the start or the exit.
+fn generate(rng: &mut Rng, bytes: usize) -> String {
+    const DIMENSION: usize = 71;
+    let mut squares: Vec<usize> = (1..DIMENSION * DIMENSION - 1).collect();
+    rng.shuffle(&mut squares);
+
+    squares
+        .iter()
+        .take(bytes)
+        .map(|i| format!("{},{}\n", i % DIMENSION, i / DIMENSION))
+        .collect()
+}
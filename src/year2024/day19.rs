@@ -0,0 +1,150 @@
+use hashbrown::{HashMap, HashSet};
+
+use crate::advent::{Error, Log, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::validate::Grammar;
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    type Input = Towels;
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
+        let (pattern_line, designs) = input
+            .split_once("\n\n")
+            .ok_or_else(|| Error::parse("expected a blank line between the patterns and the designs"))?;
+
+        let patterns: Vec<String> = pattern_line.split(",").map(|e| e.trim().to_string()).collect();
+        if let Some(x) = patterns.iter().position(|p| p.is_empty()) {
+            return Err(Error::parse(format!("pattern {} is empty", x + 1)).at_line(0));
+        }
+
+        let designs = designs.lines().map(|line| line.to_string()).collect();
+        Ok(Towels { patterns, designs })
+    }
+
+    fn part_1(&self, input: &mut Self::Input, log: &Log) -> Result<String> {
+        let mut count_possible = 0;
+        for design in &input.designs {
+            log.step()?;
+            if is_solveable(design, &input.patterns) {
+                count_possible += 1;
+            }
+        }
+        Ok(count_possible.to_string())
+    }
+
+    fn part_2(&self, input: &mut Self::Input, log: &Log) -> Result<String> {
+        let mut total = 0;
+        let mut memo = HashMap::new();
+        for design in &input.designs {
+            log.step()?;
+            total += ways_to_solve(design, &input.patterns, &mut memo);
+        }
+        Ok(total.to_string())
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("280", "606411968721181")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("6"), Some("16"))
+    }
+
+    fn name(&self) -> &'static str {
+        "Linen Layout"
+    }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Sections(&[Grammar::Block(&["{[wubrg], ...}"]), Grammar::Lines("{[wubrg]}")])
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "designs",
+            default_size: 400,
+            generate,
+        })
+    }
+}
+
+fn is_solveable(design: &str, patterns: &Vec<String>) -> bool {
+    let mut visited = HashSet::new();
+    let mut possible_matches = vec![design];
+    while let Some(design) = possible_matches.pop() {
+        if visited.contains(&design) {
+            continue;
+        }
+        visited.insert(design);
+        for pattern in patterns {
+            if design.starts_with(pattern) {
+                let substr = &design[pattern.len()..];
+                if substr.is_empty() {
+                    return true;
+                }
+                possible_matches.push(substr)
+            }
+        }
+    }
+
+    false
+}
+
+fn ways_to_solve<'a>(design: &'a str, patterns: &Vec<String>, memo: &mut HashMap<&'a str, u64>) -> u64 {
+    if design.is_empty() {
+        return 1;
+    }
+
+    if let Some(&ways) = memo.get(&design) {
+        return ways;
+    }
+
+    let result = patterns.iter().fold(0, |acc, pattern| {
+        if design.starts_with(pattern) {
+            acc + ways_to_solve(&design[pattern.len()..], patterns, memo)
+        } else {
+            acc
+        }
+    });
+
+    memo.insert(design, result);
+    result
+}
+
+#[derive(Debug)]
+pub struct Towels {
+    patterns: Vec<String>,
+    designs: Vec<String>,
+}
+
+/// 447 towels like the real input's, which has no plain "b", and designs half made of towels and half made up.
+fn generate(rng: &mut Rng, designs: usize) -> String {
+    const COLOURS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+
+    let mut towels = vec!["w".to_string(), "u".to_string(), "r".to_string(), "g".to_string()];
+    while towels.len() < 447 {
+        let towel: String = (0..rng.between(2, 8)).map(|_| *rng.pick(&COLOURS)).collect();
+        if !towels.contains(&towel) {
+            towels.push(towel);
+        }
+    }
+    rng.shuffle(&mut towels);
+
+    let mut out = towels.join(", ");
+    out.push_str("\n\n");
+    for _ in 0..designs {
+        let length = rng.between(20, 60) as usize;
+        let mut design = String::new();
+        if rng.chance(0.5) {
+            while design.len() < length {
+                design.push_str(rng.pick(&towels).as_str());
+            }
+        } else {
+            design.extend((0..length).map(|_| *rng.pick(&COLOURS)));
+        }
+        out.push_str(&design);
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,237 @@
+use std::collections::BTreeMap;
+
+use crate::advent::{Error, Log, Param, Params, Result, Solver};
+use crate::generate::{self, Generator, Rng};
+use crate::grid::Grid;
+use crate::render::{self, Render, Rgb};
+use crate::search;
+use crate::validate::{Cells, Grammar};
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    type Input = (Maze, Vec<usize>);
+
+    fn parse(&self, input: &str, params: &Params) -> Result<Self::Input> {
+        let mut start = None;
+        let tiles = Grid::parse(input, |c, x, y| match c {
+            '#' => Ok(Tile::Wall),
+            '.' => Ok(Tile::Empty),
+            'S' => {
+                start = Some((x, y));
+                Ok(Tile::Start)
+            }
+            'E' => Ok(Tile::End),
+            _ => Err(Error::parse(format!("invalid tile {:?}", c))),
+        })?;
+
+        let (x, y) = start.ok_or_else(|| Error::parse("no start found"))?;
+        let mut maze = Maze {
+            start: tiles.index_of(x, y),
+            tiles,
+            min_savings: params.get("min_savings"),
+        };
+
+        let path = update_path(&mut maze)?;
+
+        Ok((maze, path))
+    }
+
+    fn part_1(&self, input: &mut Self::Input, log: &Log) -> Result<String> {
+        log.trace(format_args!("path: {:?}", input.1));
+        log.render(
+            "track",
+            &Track {
+                maze: &input.0,
+                length: input.1.len(),
+            },
+        )?;
+        Ok(find_cheats(&input.0, &input.1, 2, log)?.to_string())
+    }
+
+    fn part_2(&self, input: &mut Self::Input, log: &Log) -> Result<String> {
+        Ok(find_cheats(&input.0, &input.1, 20, log)?.to_string())
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("1307", "986545")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("1"), Some("285"))
+    }
+
+    fn name(&self) -> &'static str {
+        "Race Condition"
+    }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Grid(Cells {
+            allowed: "#.SE",
+            exactly: &[("S", 1), ("E", 1)],
+            size: None,
+        })
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "rows and columns",
+            default_size: 141,
+            generate,
+        })
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[Param {
+            name: "min_savings",
+            sample: 50,
+            real: 100,
+        }]
+    }
+}
+
+/// Numbers each tile of the track by how far along it is.
+fn update_path(maze: &mut Maze) -> Result<Vec<usize>> {
+    let tiles = &maze.tiles;
+    let path = search::bfs(
+        maze.start,
+        |&pos| tiles.neighbors(pos).filter(|&n| tiles[n] != Tile::Wall),
+        |&pos| tiles[pos] == Tile::End,
+    )
+    .ok_or_else(|| Error::parse("couldn't find a single path from S to E"))?
+    .states();
+
+    for (i, pos) in path.iter().enumerate() {
+        maze.tiles[*pos] = Tile::Path(i);
+    }
+    Ok(path)
+}
+
+fn find_cheats(maze: &Maze, path: &[usize], cheat_max: usize, log: &Log) -> Result<u32> {
+    let mut savings = 0;
+    // how many cheats save each number of picoseconds, like the puzzle's own examples list them
+    let mut counts = log.enabled(1).then(BTreeMap::new);
+
+    for (start_index, pos) in path.iter().enumerate() {
+        log.step()?;
+        maze.for_each_manhattan_tile(*pos, cheat_max, |steps, tile| {
+            if let Tile::Path(end_index) = tile {
+                if *end_index > start_index + steps {
+                    let saved = end_index - start_index - steps;
+                    if saved >= maze.min_savings {
+                        savings += 1;
+                        if let Some(counts) = &mut counts {
+                            *counts.entry(saved).or_insert(0) += 1;
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    for (saved, count) in counts.into_iter().flatten() {
+        log.debug(format_args!("{} cheats of up to {} save {} picoseconds", count, cheat_max, saved));
+    }
+
+    Ok(savings)
+}
+
+#[derive(Debug)]
+pub struct Maze {
+    pub tiles: Grid<Tile>,
+    pub start: usize,
+    pub min_savings: usize,
+}
+
+impl Maze {
+    pub fn for_each_manhattan_tile<F>(&self, start_pos: usize, range: usize, mut f: F)
+    where
+        F: FnMut(usize, &Tile),
+    {
+        let w = self.tiles.width() as i32;
+        let h = self.tiles.height() as i32;
+        let r = range as i32;
+
+        let (x0, y0) = self.tiles.xy(start_pos);
+        let (x0, y0) = (x0 as i32, y0 as i32);
+
+        for dy in -r..=r {
+            let y = y0 + dy;
+            if y < 0 || y >= h {
+                continue;
+            }
+
+            let span = r - dy.abs();
+            let mut x1 = x0 - span;
+            let mut x2 = x0 + span;
+
+            if x1 < 0 {
+                x1 = 0;
+            }
+            if x2 >= w {
+                x2 = w - 1;
+            }
+
+            let row = &self.tiles.row(y as usize)[x1 as usize..=x2 as usize];
+            for (x, tile) in (x1..).zip(row) {
+                let dist = (x - x0).abs() + dy.abs();
+                f(dist as usize, tile);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Tile {
+    Wall,
+    Empty,
+    Path(usize),
+    Start,
+    End,
+}
+
+/// The maze with the track shaded from blue at the start to red at the end.
+struct Track<'a> {
+    maze: &'a Maze,
+    length: usize,
+}
+
+impl Render for Track<'_> {
+    fn size(&self) -> (usize, usize) {
+        (self.maze.tiles.width(), self.maze.tiles.height())
+    }
+
+    fn cell(&self, x: usize, y: usize) -> (char, Rgb) {
+        match self.maze.tiles[self.maze.tiles.index_of(x, y)] {
+            Tile::Wall => ('#', render::GREY),
+            Tile::Path(i) => {
+                let along = (i * 255 / self.length.max(1)) as u8;
+                let c = match i {
+                    0 => 'S',
+                    i if i + 1 == self.length => 'E',
+                    _ => '.',
+                };
+                (c, [along, 64, 255 - along])
+            }
+            Tile::Start => ('S', render::BLUE),
+            Tile::End => ('E', render::RED),
+            Tile::Empty => ('.', render::BLACK),
+        }
+    }
+}
+
+/// The one way through a maze, from the bottom left corner to the top right, with every other square walled up.
+fn generate(rng: &mut Rng, side: usize) -> String {
+    let open = generate::maze(rng, side, side);
+    let start = open.index_of(1, open.height() - 2);
+    let end = open.index_of(open.width() - 2, 1);
+
+    let path = search::bfs(start, |&i| open.neighbors(i).filter(|&n| open[n]), |&i| i == end).expect("mazes are connected");
+    let mut track = open.map(|_| '#');
+    for i in path.states() {
+        track[i] = '.';
+    }
+    track[start] = 'S';
+    track[end] = 'E';
+    track.to_string()
+}
@@ -1,4 +1,6 @@
-use crate::advent::Solver;
+use crate::advent::{parse_num, Error, Log, Param, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::validate::Grammar;
 use std::collections::{BTreeMap, HashMap, HashSet};
 
 pub struct Day21;
@@ -6,47 +8,101 @@ pub struct Day21;
 impl Solver for Day21 {
     type Input = Puzzle;
 
-    fn parse(&self, input: &str, _is_sample: bool) -> Self::Input {
+    fn parse(&self, input: &str, params: &Params) -> Result<Self::Input> {
         let seqs = input
             .lines()
-            .filter(|line| !line.is_empty())
-            .map(|line| line.chars().collect::<Vec<char>>())
-            .collect();
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(y, line)| {
+                // the digits are multiplied into the answer, so a longer code than the keypad's three digits and an A
+                // could overflow it
+                let code: Vec<char> = line.chars().collect();
+                let wrong = (0..code.len().max(4)).find(|&x| match code.get(x) {
+                    Some(c) if x < 3 => !c.is_ascii_digit(),
+                    Some(&c) if x == 3 => c != 'A',
+                    _ => true,
+                });
+                match wrong {
+                    Some(x) => Err(Error::parse("expected three digits and an A, like \"029A\"").at(y, x)),
+                    None => Ok(code),
+                }
+            })
+            .collect::<Result<_>>()?;
 
         let numeric_mapping = compute_numeric_mapping();
         let direction_mapping = compute_direction_mapping();
 
-        Puzzle {
+        Ok(Puzzle {
             seqs,
             numeric_mapping,
             direction_mapping,
-        }
+            robots_1: robots(params, "robots_1")?,
+            robots_2: robots(params, "robots_2")?,
+        })
     }
 
-    fn part_1(&self, input: &mut Self::Input) -> String {
-        solve(input, 2).to_string()
+    fn part_1(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        Ok(solve(input, input.robots_1)?.to_string())
     }
 
-    fn part_2(&self, input: &mut Self::Input) -> String {
-        solve(input, 25).to_string()
+    fn part_2(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        Ok(solve(input, input.robots_2)?.to_string())
     }
 
     fn expected(&self) -> (&'static str, &'static str) {
         ("238078", "293919502998014")
     }
 
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("126384"), Some("154115708116294"))
+    }
+
     fn name(&self) -> &'static str {
         "Keypad Conundrum"
     }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Lines("{d}{d}{d}A")
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "codes",
+            default_size: 5,
+            generate,
+        })
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[
+            Param {
+                name: "robots_1",
+                sample: 2,
+                real: 2,
+            },
+            Param {
+                name: "robots_2",
+                sample: 25,
+                real: 25,
+            },
+        ]
+    }
 }
 
+#[derive(Debug)]
 pub struct Puzzle {
     seqs: Vec<Vec<char>>,
     numeric_mapping: HashMap<(char, char), Vec<char>>,
     direction_mapping: HashMap<(char, char), Vec<char>>,
+    robots_1: u16,
+    robots_2: u16,
 }
 
-fn solve(puzzle: &Puzzle, direction_layers: u16) -> u64 {
+fn robots(params: &Params, name: &str) -> Result<u16> {
+    u16::try_from(params.get(name)).map_err(|_| Error::unsolvable(format!("{} is more robots than will ever finish", params.get(name))))
+}
+
+fn solve(puzzle: &Puzzle, direction_layers: u16) -> Result<u64> {
     let mut total = 0;
     let mut cache = HashMap::new();
 
@@ -54,12 +110,12 @@ fn solve(puzzle: &Puzzle, direction_layers: u16) -> u64 {
         let numeric = next_layer(seq.clone(), &puzzle.numeric_mapping);
         let shortest_length = solve_seq(numeric, direction_layers, &puzzle.direction_mapping, &mut cache);
 
-        let numeric = seq.iter().filter(|&c| c.is_numeric()).collect::<String>().parse::<u64>().unwrap();
+        let numeric: u64 = parse_num(&seq.iter().filter(|&c| c.is_numeric()).collect::<String>())?;
         let subtotal = numeric * shortest_length;
 
         total += subtotal;
     }
-    total
+    Ok(total)
 }
 
 fn pairs(input: Vec<char>) -> Vec<(char, char)> {
@@ -230,3 +286,7 @@ fn compute_mapping(locations: BTreeMap<char, (usize, usize)>) -> HashMap<(char,
 
     map
 }
+
+fn generate(rng: &mut Rng, codes: usize) -> String {
+    (0..codes).map(|_| format!("{:03}A\n", rng.below(1000))).collect()
+}
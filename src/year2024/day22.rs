@@ -0,0 +1,129 @@
+use hashbrown::HashMap;
+
+use crate::advent::{parse_num, Log, Param, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::validate::Grammar;
+
+pub struct Day22;
+
+impl Solver for Day22 {
+    type Input = Buyers;
+
+    fn parse(&self, input: &str, params: &Params) -> Result<Self::Input> {
+        let secrets = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(y, line)| parse_num(line).map_err(|e| e.at(y, 0)))
+            .collect::<Result<_>>()?;
+
+        Ok(Buyers {
+            secrets,
+            rounds: params.get("rounds"),
+        })
+    }
+
+    fn part_1(&self, input: &mut Self::Input, log: &Log) -> Result<String> {
+        let mut total: u64 = 0;
+        for secret in &input.secrets {
+            log.step()?;
+            let mut accum = *secret;
+            for _ in 0..input.rounds {
+                accum = next(accum);
+            }
+
+            total += accum as u64;
+        }
+
+        Ok(total.to_string())
+    }
+
+    fn part_2(&self, input: &mut Self::Input, log: &Log) -> Result<String> {
+        let mut map: HashMap<[i32; 4], (usize, u64)> = HashMap::new();
+
+        for (secret_index, secret_start) in input.secrets.iter_mut().enumerate() {
+            log.step()?;
+            let mut three: i32 = 0;
+            let mut two: i32 = 0;
+            let mut one: i32 = 0;
+            let mut previous = 0;
+            let mut next_secret = *secret_start;
+            // one extra loop because we need the digit from the seed
+            for iteration in 0..=input.rounds {
+                let last_digit = (next_secret % 10) as i32;
+                let delta = last_digit - previous;
+                let last_four = [three, two, one, delta];
+
+                if iteration > 3 {
+                    map.entry(last_four)
+                        .and_modify(|(index, total)| {
+                            if *index != secret_index {
+                                *index = secret_index;
+                                *total += last_digit as u64;
+                            }
+                        })
+                        .or_insert((secret_index, last_digit as u64));
+                }
+
+                previous = last_digit;
+                three = two;
+                two = one;
+                one = delta;
+                next_secret = next(next_secret);
+            }
+        }
+
+        let total = map.values().map(|(_, total)| *total).max().unwrap_or(0);
+
+        Ok(total.to_string())
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("13185239446", "1501")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("37990510"), Some("23"))
+    }
+
+    fn name(&self) -> &'static str {
+        "Monkey Market"
+    }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Lines("{n}")
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "buyers",
+            default_size: 1500,
+            generate,
+        })
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[Param {
+            name: "rounds",
+            sample: 2000,
+            real: 2000,
+        }]
+    }
+}
+
+const MASK: u32 = 0x00ffffff;
+
+pub struct Buyers {
+    secrets: Vec<u32>,
+    rounds: usize,
+}
+
+fn next(secret: u32) -> u32 {
+    let secret = ((secret << 6) ^ secret) & MASK;
+    let secret = ((secret >> 5) ^ secret) & MASK;
+    ((secret << 11) ^ secret) & MASK
+}
+
+fn generate(rng: &mut Rng, buyers: usize) -> String {
+    (0..buyers).map(|_| format!("{}\n", rng.between(1, (1 << 24) - 1))).collect()
+}
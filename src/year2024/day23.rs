@@ -1,27 +1,28 @@
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
 
-use crate::advent::Solver;
+use crate::advent::{Error, Log, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::validate::Grammar;
 
 pub struct Day23;
 
 impl Solver for Day23 {
     type Input = HashSet<(String, String)>;
 
-    fn parse(&self, input: &str, _is_sample: bool) -> Self::Input {
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
         input
             .lines()
-            .filter(|line| !line.is_empty())
-            .map(|s| {
-                let mut split = s.split("-");
-                let first = split.next().unwrap().trim().to_string();
-                let second = split.next().unwrap().trim().to_string();
-                (first, second)
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(y, s)| {
+                let (first, second) = s.split_once("-").ok_or_else(|| Error::parse("expected \"<a>-<b>\"").at_line(y))?;
+                Ok((first.trim().to_string(), second.trim().to_string()))
             })
             .collect()
     }
 
-    fn part_1(&self, input: &mut Self::Input) -> String {
+    fn part_1(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
         let mut conn_map: HashMap<&str, HashSet<&str>> = HashMap::new();
         for (first, second) in input.iter() {
             conn_map.entry(first).or_insert(HashSet::new()).insert(second);
@@ -42,10 +43,10 @@ impl Solver for Day23 {
             }
         }
 
-        triplets.len().to_string()
+        Ok(triplets.len().to_string())
     }
 
-    fn part_2(&self, input: &mut Self::Input) -> String {
+    fn part_2(&self, input: &mut Self::Input, log: &Log) -> Result<String> {
         let mut conn_map: HashMap<&str, imbl::HashSet<&str>> = HashMap::new();
         for (first, second) in input.iter() {
             conn_map.entry(first).or_insert(imbl::HashSet::new()).insert(second);
@@ -58,6 +59,7 @@ impl Solver for Day23 {
         let empty = imbl::HashSet::new();
 
         for (node, neighbors) in conn_map.iter() {
+            log.step()?;
             let mut stack = vec![Candidate {
                 next: node,
                 path: empty.update(node),
@@ -95,16 +97,34 @@ impl Solver for Day23 {
                 }
             }
         }
-        max_found.unwrap().iter().sorted().join(",")
+        max_found
+            .map(|found| found.iter().sorted().join(","))
+            .ok_or_else(|| Error::unsolvable("there are no connections"))
     }
 
     fn expected(&self) -> (&'static str, &'static str) {
         ("1476", "ca,dw,fo,if,ji,kg,ks,oe,ov,sb,ud,vr,xr")
     }
 
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("7"), Some("co,de,ka,ta"))
+    }
+
     fn name(&self) -> &'static str {
         "LAN Party"
     }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Lines("{[a-z]}-{[a-z]}")
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "computers",
+            default_size: 520,
+            generate,
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -113,3 +133,37 @@ struct Candidate<'a> {
     path: imbl::HashSet<&'a str>,
     pool: imbl::HashSet<&'a str>,
 }
+
+/// About 13 connections a computer, like the real network, with one 13-computer party hidden in it. There are only
+/// 676 two-letter names, so there can't be more computers than that.
+fn generate(rng: &mut Rng, computers: usize) -> String {
+    let mut names: Vec<String> = ('a'..='z').flat_map(|a| ('a'..='z').map(move |b| format!("{}{}", a, b))).collect();
+    rng.shuffle(&mut names);
+    names.truncate(computers.clamp(2, names.len()));
+
+    let n = names.len();
+    let mut links = Vec::new();
+    for i in 0..n {
+        for _ in 0..6 {
+            let j = rng.below(n);
+            if i != j {
+                links.push((i.min(j), i.max(j)));
+            }
+        }
+    }
+    let party = n.min(13);
+    for i in 0..party {
+        for j in i + 1..party {
+            links.push((i, j));
+        }
+    }
+    links.sort_unstable();
+    links.dedup();
+    rng.shuffle(&mut links);
+
+    links
+        .into_iter()
+        .map(|(i, j)| if rng.chance(0.5) { (i, j) } else { (j, i) })
+        .map(|(i, j)| format!("{}-{}\n", names[i], names[j]))
+        .collect()
+}
@@ -0,0 +1,364 @@
+use std::fmt::Display;
+
+use hashbrown::{HashMap, HashSet};
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::advent::{Error, Log, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::validate::Grammar;
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    type Input = Circuit;
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
+        let (state, connections) = input
+            .split_once("\n\n")
+            .ok_or_else(|| Error::parse("expected a blank line between the wires and the gates"))?;
+        let first_connection_line = state.lines().count() + 1;
+
+        let state_regex = Regex::new(r"^(\w+): ([01])$").unwrap();
+        let state = state
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(y, line)| {
+                let capture = state_regex
+                    .captures(line)
+                    .ok_or_else(|| Error::parse("expected \"<wire>: <0 or 1>\"").at_line(y))?;
+                Ok((Wire::new(&capture[1]).map_err(|e| e.at_line(y))?, &capture[2] == "1"))
+            })
+            .collect::<Result<_>>()?;
+
+        let connection_regex = Regex::new(r"^(\w+) (\w+) (\w+) -> (\w+)$").unwrap();
+        let connections = connections
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| {
+                let y = first_connection_line + i;
+                let capture = connection_regex
+                    .captures(line)
+                    .ok_or_else(|| Error::parse("expected \"<wire> <op> <wire> -> <wire>\"").at_line(y))?;
+                let wire = |i: usize| Wire::new(&capture[i]).map_err(|e| e.at(y, capture.get(i).unwrap().start()));
+                Ok(Gate {
+                    left_wire: wire(1)?,
+                    right_wire: wire(3)?,
+                    op: match &capture[2] {
+                        "AND" => GateType::And,
+                        "OR" => GateType::Or,
+                        "XOR" => GateType::Xor,
+                        op => return Err(Error::parse(format!("invalid operation {:?}", op)).at(y, capture.get(2).unwrap().start())),
+                    },
+                    output_wire: wire(4)?,
+                    left_value: None,
+                    right_value: None,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Circuit { state, gates: connections })
+    }
+
+    fn part_1(&self, input: &mut Self::Input, log: &Log) -> Result<String> {
+        let mut connections: HashMap<Wire, Vec<(usize, GateSide)>> = HashMap::new();
+        for (idx, gate) in input.gates.iter_mut().enumerate() {
+            gate.left_value = None;
+            gate.right_value = None;
+
+            connections.entry(gate.left_wire.clone()).or_default().push((idx, GateSide::Left));
+            connections.entry(gate.right_wire.clone()).or_default().push((idx, GateSide::Right));
+        }
+
+        let mut stack: Vec<(Wire, bool)> = input.state.clone().into_iter().collect::<Vec<_>>();
+        let mut result = HashMap::<u32, bool>::new();
+
+        // a wire that feeds back into itself keeps the stack going for as long as the log's step limit lets it
+        while let Some((wire, val)) = stack.pop() {
+            log.step()?;
+            if let Some(conns) = connections.get(&wire) {
+                for &(idx, side) in conns {
+                    let gate = &mut input.gates[idx];
+                    match side {
+                        GateSide::Left => gate.left_value = Some(val),
+                        GateSide::Right => gate.right_value = Some(val),
+                    }
+
+                    if let (Some(left), Some(right)) = (gate.left_value, gate.right_value) {
+                        let value = match gate.op {
+                            GateType::And => left && right,
+                            GateType::Or => left || right,
+                            GateType::Xor => left ^ right,
+                        };
+
+                        if let Wire::Output(z_val) = &gate.output_wire {
+                            result.insert(*z_val, value);
+                        } else {
+                            stack.push((gate.output_wire.clone(), value));
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut final_number: u64 = 0;
+        for (idx, value) in result {
+            if value {
+                let shifted = 1 << idx;
+                final_number |= shifted;
+            }
+        }
+
+        Ok(final_number.to_string())
+    }
+
+    fn part_2(&self, input: &mut Self::Input, _log: &Log) -> Result<String> {
+        let mut connections: HashMap<Wire, Vec<&Gate>> = HashMap::new();
+        for gate in &input.gates {
+            connections.entry(gate.left_wire.clone()).or_default().push(gate);
+            connections.entry(gate.right_wire.clone()).or_default().push(gate);
+        }
+
+        let gate = |wire: &Wire, t: GateType| {
+            find_gate(&connections, wire, t.clone())
+                .ok_or_else(|| Error::unsolvable(format!("no {:?} gate reads {}; is this an adder?", t, wire)))
+        };
+
+        // assume blithly that there's no issue in the first bit, but we still need the carry wire
+        let and = gate(&Wire::Input('x', 0), GateType::And)?;
+        let mut prev_carry_wire = and.output_wire.clone();
+
+        let mut bad_wires = Vec::new();
+
+        for i in 1..45 {
+            let mut bad_this_iteration = None;
+            let x_wire = Wire::Input('x', i);
+
+            // we're going to assume xn and yn are never swapped with anything
+            let xor1 = gate(&x_wire, GateType::Xor)?;
+            let and1 = gate(&x_wire, GateType::And)?;
+
+            // also assume the incoming carry isn't swapped, because if it is, we'll find it the round before and provide the swapped one
+            let xor2 = gate(&prev_carry_wire, GateType::Xor)?;
+            let and2 = gate(&prev_carry_wire, GateType::And)?;
+
+            // whatever xor1's output is, it had better be hooked up to xor2
+            if xor2.left_wire == prev_carry_wire && xor2.right_wire != xor1.output_wire
+                || xor2.right_wire == prev_carry_wire && xor2.left_wire != xor1.output_wire
+            {
+                bad_wires.push(xor1.output_wire.clone());
+                bad_this_iteration = Some(xor1.output_wire.clone());
+            }
+
+            // xor2 produces the output value, so if it's not an output, it's wrong
+            if !xor2.output_wire.is_output() {
+                bad_wires.push(xor2.output_wire.clone());
+                bad_this_iteration = Some(xor2.output_wire.clone());
+            }
+
+            // the last one doesn't have a carry
+            if i == 44 {
+                continue;
+            }
+
+            let or = match find_gate(&connections, &and2.output_wire, GateType::Or) {
+                None => {
+                    bad_wires.push(and2.output_wire.clone());
+                    bad_this_iteration = Some(and2.output_wire.clone());
+
+                    // if we couldn't find the or from and2, then we need to find it from and1's output; they can't both be wrong
+                    gate(&and1.output_wire, GateType::Or)?
+                }
+                Some(or) => {
+                    // make sure and1 is the other side of it
+                    if or.left_wire == and2.output_wire && or.right_wire != and1.output_wire
+                        || or.right_wire == and2.output_wire && or.left_wire != and1.output_wire
+                    {
+                        bad_wires.push(and1.output_wire.clone());
+                        bad_this_iteration = Some(and1.output_wire.clone());
+                    }
+                    or
+                }
+            };
+
+            if or.output_wire.is_output() {
+                bad_wires.push(or.output_wire.clone());
+                prev_carry_wire =
+                    bad_this_iteration.ok_or_else(|| Error::unsolvable("found a swapped carry with nothing to swap it with"))?;
+            } else {
+                prev_carry_wire = or.output_wire.clone();
+            }
+        }
+
+        Ok(bad_wires.into_iter().map(|x| x.to_string()).sorted().join(","))
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("42410633905894", "cqm,mps,vcv,vjv,vwp,z13,z19,z25")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("2024"), None)
+    }
+
+    fn name(&self) -> &'static str {
+        "Crossed Wires"
+    }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Sections(&[
+            Grammar::Lines("{[a-z0-9]}: {[01]}"),
+            Grammar::Lines("{[a-z0-9]} {[A-Z]} {[a-z0-9]} -> {[a-z0-9]}"),
+        ])
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "swapped pairs",
+            default_size: 4,
+            generate,
+        })
+    }
+}
+
+fn find_gate<'a>(connections: &'a HashMap<Wire, Vec<&'a Gate>>, one_side: &Wire, t: GateType) -> Option<&'a Gate> {
+    connections
+        .get(one_side)
+        .into_iter()
+        .flatten()
+        .find(|&gate| (gate.left_wire == *one_side || gate.right_wire == *one_side) && gate.op == t)
+        .copied()
+}
+
+#[derive(Debug, Clone)]
+pub struct Circuit {
+    pub state: Vec<(Wire, bool)>,
+    pub gates: Vec<Gate>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Gate {
+    left_wire: Wire,
+    right_wire: Wire,
+    output_wire: Wire,
+    op: GateType,
+    left_value: Option<bool>,
+    right_value: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum GateSide {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum GateType {
+    Or,
+    Xor,
+    And,
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub enum Wire {
+    Input(char, u32),
+    Output(u32),
+    Intermediate(heapless::String<3>),
+}
+
+impl Wire {
+    pub fn new(s: &str) -> Result<Self> {
+        let first_char = s.chars().next().ok_or_else(|| Error::parse("expected a wire name"))?;
+        let number = s[first_char.len_utf8()..].parse().ok();
+
+        match (first_char, number) {
+            ('x' | 'y', Some(n)) => Ok(Wire::Input(first_char, n)),
+            // each output is a bit of part 1's answer, which is a u64
+            ('z', Some(n)) if n >= u64::BITS => Err(Error::parse(format!("output wire {:?} is past z63, the last bit there is", s))),
+            ('z', Some(n)) => Ok(Wire::Output(n)),
+            _ => s
+                .parse()
+                .map(Wire::Intermediate)
+                .map_err(|_| Error::parse(format!("wire name {:?} is longer than 3 characters", s))),
+        }
+    }
+
+    pub fn is_output(&self) -> bool {
+        match self {
+            Wire::Input(_, _) => false,
+            Wire::Output(_) => true,
+            Wire::Intermediate(_) => false,
+        }
+    }
+}
+
+impl Display for Wire {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Wire::Input(c, n) => write!(f, "{}{}", c, n),
+            Wire::Output(n) => write!(f, "z{}", n),
+            Wire::Intermediate(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// A 45-bit ripple-carry adder like the real one, with the outputs of `swaps` pairs of gates swapped, each pair within
+/// the full adder for one bit, in one of the ways the real inputs do it.
+fn generate(rng: &mut Rng, swaps: usize) -> String {
+    const BITS: usize = 45;
+
+    let mut taken = HashSet::new();
+    let mut name = || loop {
+        let name: String = (0..3).map(|_| (b'a' + rng.below(23) as u8) as char).collect();
+        if taken.insert(name.clone()) {
+            break name;
+        }
+    };
+
+    // (left, op, right, output), with the gates for each bit in the order xor, and, sum, carry-through, carry
+    let mut gates: Vec<(String, &str, String, String)> = vec![
+        ("x00".to_string(), "XOR", "y00".to_string(), "z00".to_string()),
+        ("x00".to_string(), "AND", "y00".to_string(), name()),
+    ];
+    let mut carry = gates[1].3.clone();
+    for bit in 1..BITS {
+        let (x, y, z) = (format!("x{:02}", bit), format!("y{:02}", bit), format!("z{:02}", bit));
+        let (either, both) = (name(), name());
+        let through = name();
+        let next = if bit == BITS - 1 { format!("z{:02}", BITS) } else { name() };
+        gates.push((x.clone(), "XOR", y.clone(), either.clone()));
+        gates.push((x, "AND", y, both.clone()));
+        gates.push((either.clone(), "XOR", carry.clone(), z));
+        gates.push((either, "AND", carry, through.clone()));
+        gates.push((both, "OR", through, next.clone()));
+        carry = next;
+    }
+
+    // the last bit has no carry to get wrong, so leave it alone
+    let mut bits: Vec<usize> = (1..BITS - 1).collect();
+    rng.shuffle(&mut bits);
+    for bit in bits.into_iter().take(swaps) {
+        let first = 2 + (bit - 1) * 5;
+        let (a, b) = *rng.pick(&[(0, 1), (2, 4), (2, 3), (2, 1)]);
+        let (a, b) = (first + a, first + b);
+        let output = gates[a].3.clone();
+        gates[a].3 = std::mem::replace(&mut gates[b].3, output);
+    }
+
+    let mut out = String::new();
+    for wire in ['x', 'y'] {
+        for bit in 0..BITS {
+            out.push_str(&format!("{}{:02}: {}\n", wire, bit, rng.below(2)));
+        }
+    }
+    out.push('\n');
+
+    rng.shuffle(&mut gates);
+    for (left, op, right, output) in gates {
+        let (left, right) = if rng.chance(0.5) { (left, right) } else { (right, left) };
+        out.push_str(&format!("{} {} {} -> {}\n", left, op, right, output));
+    }
+    out
+}
@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+
+use crate::advent::{Error, Log, Params, Result, Solver};
+use crate::generate::{Generator, Rng};
+use crate::validate::{Cells, Grammar};
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    type Input = LocksAndKeys;
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
+        let mut locks = Vec::new();
+        let mut keys = Vec::new();
+        let mut first_line = 0;
+        for key_or_lock in input.split("\n\n") {
+            let mut is_lock = false;
+            let mut pins = [0u8; 5];
+            let rows = key_or_lock.lines().count();
+            if rows != 7 {
+                return Err(Error::parse(format!("expected a schematic 7 rows tall, found {}", rows)).at_line(first_line));
+            }
+
+            for (i, row) in key_or_lock.lines().enumerate() {
+                if row.len() != 5 {
+                    return Err(Error::parse(format!("expected a row 5 wide, found {}", row.len())).at_line(first_line + i));
+                }
+
+                if i == 0 {
+                    is_lock = row.starts_with("#");
+                } else if !is_lock && i == 6 {
+                    // if it's a key, we don't count the last row
+                    continue;
+                } else {
+                    for (j, c) in row.chars().enumerate() {
+                        if let '#' = c {
+                            pins[j] += 1;
+                        }
+                    }
+                }
+            }
+            if is_lock {
+                locks.push(pins);
+            } else {
+                keys.push(pins);
+            }
+
+            first_line += rows + 1;
+        }
+
+        Ok(LocksAndKeys { locks, keys })
+    }
+
+    fn part_1(&self, input: &mut Self::Input, log: &Log) -> Result<String> {
+        let mut root = TrieNode { children: BTreeMap::new() };
+        for key in &input.locks {
+            let mut node = &mut root;
+            for height in key {
+                node = node.children.entry(*height).or_insert(TrieNode { children: BTreeMap::new() });
+            }
+        }
+
+        log.debug(format_args!("{} locks, {} keys", input.locks.len(), input.keys.len()));
+        log.trace(format_args!("trie of lock heights: {:#?}", root));
+
+        let mut stack = input.keys.iter().map(|k| (k, &root, 0)).collect::<Vec<_>>();
+
+        let mut total = 0;
+        while let Some((key, node, depth)) = stack.pop() {
+            if depth == 5 {
+                total += 1;
+            } else {
+                let key_height = key[depth];
+                for (_, child_node) in node.children.iter().filter(|(height, _)| 5 - key_height >= **height) {
+                    stack.push((key, child_node, depth + 1));
+                }
+            }
+        }
+
+        Ok(total.to_string())
+    }
+
+    fn part_2(&self, _input: &mut Self::Input, _log: &Log) -> Result<String> {
+        Ok("dne".to_string())
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("3619", "dne")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("3"), Some("dne"))
+    }
+
+    fn name(&self) -> &'static str {
+        "Code Chronicle"
+    }
+
+    fn grammar(&self) -> Grammar {
+        Grammar::Repeated(&Grammar::Grid(Cells {
+            allowed: "#.",
+            exactly: &[],
+            size: Some((5, 7)),
+        }))
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        Some(Generator {
+            size: "schematics",
+            default_size: 500,
+            generate,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct LocksAndKeys {
+    locks: Vec<[u8; 5]>,
+    keys: Vec<[u8; 5]>,
+}
+
+#[derive(Debug)]
+struct TrieNode {
+    children: BTreeMap<u8, TrieNode>,
+}
+
+/// Locks and keys, about half of each, with columns of any height that fits.
+fn generate(rng: &mut Rng, schematics: usize) -> String {
+    let mut out = Vec::new();
+    for _ in 0..schematics {
+        let is_lock = rng.chance(0.5);
+        let heights: Vec<usize> = (0..5).map(|_| rng.below(6)).collect();
+        let mut schematic = String::new();
+        for row in 0..7 {
+            for height in &heights {
+                let filled = if is_lock { row <= *height } else { 6 - row <= *height };
+                schematic.push(if filled { '#' } else { '.' });
+            }
+            schematic.push('\n');
+        }
+        out.push(schematic);
+    }
+    out.join("\n")
+}
@@ -0,0 +1,31 @@
+use crate::advent::{Error, Log, Params, Result, Solver};
+
+pub struct Day__NN__;
+
+impl Solver for Day__NN__ {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Self::Input> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part_1(&self, _input: &mut Self::Input, _log: &Log) -> Result<String> {
+        Err(Error::unsolvable("not solved yet"))
+    }
+
+    fn part_2(&self, _input: &mut Self::Input, _log: &Log) -> Result<String> {
+        Err(Error::unsolvable("not solved yet"))
+    }
+
+    fn expected(&self) -> (&'static str, &'static str) {
+        ("", "")
+    }
+
+    fn expected_sample(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (None, None)
+    }
+
+    fn name(&self) -> &'static str {
+        __NAME__
+    }
+}
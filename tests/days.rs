@@ -0,0 +1,96 @@
+//! Runs every day through the same registry as the binary, checking the answers for the samples and, when the input
+//! file is there, the real inputs. Inputs aren't committed, so a missing one is skipped rather than failed. Every input
+//! that's checked has to get through `validate` cleanly too, so a day's grammar can't drift from what it really reads.
+
+use advent_2024::advent::{self, Check, Day, Input, Options, Part};
+use advent_2024::generate::Rng;
+use advent_2024::{days, DEFAULT_YEAR};
+use std::path::Path;
+
+fn check(number: usize, input: Input) {
+    let day = Day {
+        number,
+        input,
+        part: Part::Both,
+    };
+
+    let options = Options {
+        check: true,
+        ..Options::default()
+    };
+
+    let path = day.path(&options);
+    if !Path::new(&path).exists() {
+        eprintln!("skipping day {}: {} doesn't exist", number, path);
+        return;
+    }
+
+    let solver = advent_2024::day(DEFAULT_YEAR, number).unwrap();
+
+    let text = advent::load_input(&path).unwrap();
+    if let Some(problem) = solver.validate(&day, &text, &options).first() {
+        panic!("{} doesn't validate: {}", path, problem);
+    }
+
+    match solver.solve(day, &options) {
+        Ok(sol) => match sol.check {
+            Check::Passed | Check::Skipped => {}
+            Check::Failed(e) => panic!("{}", e),
+            Check::Unchecked => panic!("day {} wasn't checked", number),
+        },
+        Err(e) => panic!("{}", e),
+    }
+}
+
+macro_rules! days {
+    ($($day:ident => $number:expr),* $(,)?) => {
+        $(
+            mod $day {
+                use advent_2024::advent::Input;
+
+                #[test]
+                fn sample() {
+                    super::check($number, Input::Sample);
+                }
+
+                #[test]
+                fn real() {
+                    super::check($number, Input::Real);
+                }
+            }
+        )*
+    };
+}
+
+days! {
+    day01 => 1, day02 => 2, day03 => 3, day04 => 4, day05 => 5,
+    day06 => 6, day07 => 7, day08 => 8, day09 => 9, day10 => 10,
+    day11 => 11, day12 => 12, day13 => 13, day14 => 14, day15 => 15,
+    day16 => 16, day17 => 17, day18 => 18, day19 => 19, day20 => 20,
+    day21 => 21, day22 => 22, day23 => 23, day24 => 24, day25 => 25,
+}
+
+#[test]
+fn registry_covers_every_day() {
+    assert_eq!(days(DEFAULT_YEAR).len(), 25);
+}
+
+#[test]
+fn generated_inputs_validate() {
+    let options = Options::default();
+    for (i, solver) in days(DEFAULT_YEAR).iter().enumerate() {
+        let generator = solver.generator().unwrap();
+        let day = Day {
+            number: i + 1,
+            input: Input::Real,
+            part: Part::Both,
+        };
+
+        for seed in 0..3 {
+            let input = (generator.generate)(&mut Rng::new(seed), generator.default_size);
+            if let Some(problem) = solver.validate(&day, &input, &options).first() {
+                panic!("day {} with seed {} doesn't validate: {}", i + 1, seed, problem);
+            }
+        }
+    }
+}
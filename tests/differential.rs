@@ -0,0 +1,501 @@
+//! Runs the solvers that lean on clever tricks against slow references that take no shortcuts, on many small inputs
+//! from the days' own generators. The first input they disagree on is shrunk, a line or a character at a time, to
+//! something small enough to debug by hand, keeping it well-formed by the day's grammar along the way.
+
+use advent_2024::advent::{Day, Input, Log, Options, Params, Part, Result, Solver};
+use advent_2024::generate::Rng;
+use advent_2024::year2024::{day05::Day05, day06::Day06, day09::Day09, day13::Day13, day17::Day17};
+use advent_2024::DEFAULT_YEAR;
+use hashbrown::HashSet;
+use std::panic::{self, AssertUnwindSafe};
+
+const SEEDS: u64 = 200;
+
+/// More than enough to get any of these down to a few lines, and a bound on how long a failure takes to report.
+const MAX_SHRINK_ATTEMPTS: usize = 20_000;
+
+struct Case {
+    day: usize,
+    /// For the day's generator, kept small so that the references are quick and the failures readable.
+    size: usize,
+    solve: fn(&str) -> Result<String>,
+    /// What the answer should be, or `None` for an input that breaks the puzzle's promises, like a guard that never
+    /// leaves, which the solver can't be blamed for.
+    reference: fn(&str) -> Option<String>,
+}
+
+fn run<S: Solver>(solver: S, number: usize, part: u8, input: &str) -> Result<String> {
+    let mut parsed = solver.parse(input, &Params::new(solver.params(), Input::Real))?;
+    let log = Log::new(number, 0);
+    match part {
+        1 => solver.part_1(&mut parsed, &log),
+        _ => solver.part_2(&mut parsed, &log),
+    }
+}
+
+fn check(case: Case) {
+    let solver = advent_2024::day(DEFAULT_YEAR, case.day).unwrap();
+    let generator = solver.generator().unwrap();
+    let day = Day {
+        number: case.day,
+        input: Input::Real,
+        part: Part::Both,
+    };
+    let options = Options::default();
+
+    // what the solver says, with panics as answers of their own so they get shrunk like any other disagreement
+    let solve = |input: &str| -> String {
+        match panic::catch_unwind(AssertUnwindSafe(|| (case.solve)(input))) {
+            Ok(Ok(answer)) => answer,
+            Ok(Err(e)) => format!("error: {}", e),
+            Err(_) => "panicked".to_string(),
+        }
+    };
+    let disagrees = |input: &str| {
+        solver.validate(&day, input, &options).is_empty() && (case.reference)(input).is_some_and(|expected| expected != solve(input))
+    };
+
+    // the shrinking tries out plenty of inputs that panic, and the default hook would print every one
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let failure = (0..SEEDS).find_map(|seed| {
+        let input = (generator.generate)(&mut Rng::new(seed), case.size);
+        disagrees(&input).then(|| (seed, input.len(), shrink(input, disagrees)))
+    });
+    panic::set_hook(hook);
+
+    if let Some((seed, length, input)) = failure {
+        panic!(
+            "day {} disagrees with the reference on seed {}, shrunk from {} bytes to {}:\n{}\nreference: {}\nsolver:    {}",
+            case.day,
+            seed,
+            length,
+            input.len(),
+            input,
+            (case.reference)(&input).unwrap(),
+            solve(&input)
+        );
+    }
+}
+
+/// Keeps taking the first smaller version of `input` that still fails, until none do.
+fn shrink(mut input: String, fails: impl Fn(&str) -> bool) -> String {
+    let mut attempts = 0;
+    'smaller: loop {
+        for candidate in smaller(&input) {
+            attempts += 1;
+            if attempts > MAX_SHRINK_ATTEMPTS {
+                break 'smaller;
+            }
+            if fails(&candidate) {
+                input = candidate;
+                continue 'smaller;
+            }
+        }
+        break;
+    }
+    input
+}
+
+/// Smaller versions of `input`, roughly the biggest cuts first: sections between blank lines, runs of lines, single
+/// characters, numbers made smaller, then characters made into the most common one, which for a grid is usually the
+/// empty square.
+fn smaller(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    let rejoin = |lines: &[&str]| lines.iter().map(|line| format!("{}\n", line)).collect::<String>();
+    let mut candidates = Vec::new();
+
+    let sections: Vec<&str> = input.split("\n\n").collect();
+    if sections.len() > 1 {
+        for i in 0..sections.len() {
+            let fewer: Vec<&str> = sections
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, section)| *section)
+                .collect();
+            candidates.push(fewer.join("\n\n"));
+        }
+    }
+
+    let mut chunk = lines.len() / 2;
+    while chunk > 0 {
+        for start in (0..lines.len()).step_by(chunk) {
+            let mut fewer = lines.clone();
+            fewer.drain(start..(start + chunk).min(lines.len()));
+            candidates.push(rejoin(&fewer));
+        }
+        chunk /= 2;
+    }
+
+    let chars: Vec<char> = input.chars().collect();
+    for i in 0..chars.len() {
+        if chars[i] != '\n' {
+            candidates.push(chars[..i].iter().chain(&chars[i + 1..]).collect());
+        }
+    }
+
+    let mut start = 0;
+    while start < chars.len() {
+        if !chars[start].is_ascii_digit() {
+            start += 1;
+            continue;
+        }
+        let end = (start..chars.len()).find(|&i| !chars[i].is_ascii_digit()).unwrap_or(chars.len());
+        let digits: String = chars[start..end].iter().collect();
+        if let Ok(n) = digits.parse::<u64>() {
+            for less in [0, 1, n / 2, n - n.min(1)].into_iter().filter(|&less| less < n) {
+                let before: String = chars[..start].iter().collect();
+                let after: String = chars[end..].iter().collect();
+                candidates.push(format!("{}{}{}", before, less, after));
+            }
+        }
+        start = end;
+    }
+
+    let most_common = chars
+        .iter()
+        .filter(|&&c| c != '\n')
+        .max_by_key(|&&c| chars.iter().filter(|&&d| d == c).count());
+    if let Some(&common) = most_common {
+        for i in 0..chars.len() {
+            if chars[i] != common && chars[i] != '\n' {
+                let mut replaced = chars.clone();
+                replaced[i] = common;
+                candidates.push(replaced.into_iter().collect());
+            }
+        }
+    }
+
+    candidates
+}
+
+fn numbers(line: &str) -> Vec<i64> {
+    line.split(|c: char| !c.is_ascii_digit())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse().unwrap())
+        .collect()
+}
+
+/// The rules and the updates, or `None` if an update's order isn't settled by the rules, since then there's no one
+/// middle page.
+fn print_queue(input: &str) -> Option<(usize, usize)> {
+    let (rules, updates) = input.split_once("\n\n")?;
+    let rules: HashSet<(i64, i64)> = rules.lines().map(|line| (numbers(line)[0], numbers(line)[1])).collect();
+
+    let (mut correct, mut fixed) = (0, 0);
+    for update in updates.lines().map(numbers) {
+        let in_order = (0..update.len()).all(|i| (i + 1..update.len()).all(|j| !rules.contains(&(update[j], update[i]))));
+
+        // pick whichever page nothing left has to come before, one at a time
+        let mut left = update.clone();
+        let mut ordered = Vec::new();
+        while !left.is_empty() {
+            let firsts: Vec<usize> = (0..left.len())
+                .filter(|&i| left.iter().all(|&other| !rules.contains(&(other, left[i]))))
+                .collect();
+            if firsts.len() != 1 {
+                return None;
+            }
+            ordered.push(left.remove(firsts[0]));
+        }
+
+        if in_order {
+            correct += update[update.len() / 2] as usize;
+        } else {
+            fixed += ordered[ordered.len() / 2] as usize;
+        }
+    }
+    Some((correct, fixed))
+}
+
+#[test]
+fn day05_part_1() {
+    check(Case {
+        day: 5,
+        size: 10,
+        solve: |input| run(Day05, 5, 1, input),
+        reference: |input| print_queue(input).map(|(correct, _)| correct.to_string()),
+    });
+}
+
+#[test]
+fn day05_part_2() {
+    check(Case {
+        day: 5,
+        size: 10,
+        solve: |input| run(Day05, 5, 2, input),
+        reference: |input| print_queue(input).map(|(_, fixed)| fixed.to_string()),
+    });
+}
+
+/// Where the guard goes, or `None` if they go round in circles.
+fn patrol(lab: &[Vec<char>]) -> Option<HashSet<(usize, usize)>> {
+    let (mut x, mut y) = (0..lab.len()).find_map(|y| lab[y].iter().position(|&c| c == '^').map(|x| (x as i64, y as i64)))?;
+    let (mut dx, mut dy) = (0, -1);
+    let mut seen = HashSet::new();
+    loop {
+        if !seen.insert((x, y, dx, dy)) {
+            return None;
+        }
+        let (nx, ny) = (x + dx, y + dy);
+        if ny < 0 || nx < 0 || ny as usize >= lab.len() || nx as usize >= lab[0].len() {
+            return Some(seen.into_iter().map(|(x, y, _, _)| (x as usize, y as usize)).collect());
+        }
+        if lab[ny as usize][nx as usize] == '#' {
+            (dx, dy) = (-dy, dx);
+        } else {
+            (x, y) = (nx, ny);
+        }
+    }
+}
+
+#[test]
+fn day06_part_1() {
+    check(Case {
+        day: 6,
+        size: 10,
+        solve: |input| run(Day06, 6, 1, input),
+        reference: |input| {
+            let lab: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+            patrol(&lab).map(|visited| visited.len().to_string())
+        },
+    });
+}
+
+#[test]
+fn day06_part_2() {
+    check(Case {
+        day: 6,
+        size: 10,
+        solve: |input| run(Day06, 6, 2, input),
+        reference: |input| {
+            let mut lab: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+            patrol(&lab)?;
+
+            // an obstacle in every empty square in turn
+            let mut loops = 0;
+            for y in 0..lab.len() {
+                for x in 0..lab[y].len() {
+                    if lab[y][x] == '.' {
+                        lab[y][x] = '#';
+                        loops += usize::from(patrol(&lab).is_none());
+                        lab[y][x] = '.';
+                    }
+                }
+            }
+            Some(loops.to_string())
+        },
+    });
+}
+
+/// The disk a block at a time, with the file id in each or `None` where it's free.
+fn disk(input: &str) -> Vec<Option<usize>> {
+    let mut blocks = Vec::new();
+    for (i, c) in input.trim_end().chars().enumerate() {
+        let id = (i % 2 == 0).then_some(i / 2);
+        blocks.extend(std::iter::repeat_n(id, c.to_digit(10).unwrap() as usize));
+    }
+    blocks
+}
+
+fn checksum(blocks: &[Option<usize>]) -> String {
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(i, id)| i * id.unwrap_or(0))
+        .sum::<usize>()
+        .to_string()
+}
+
+#[test]
+fn day09_part_1() {
+    check(Case {
+        day: 9,
+        size: 21,
+        solve: |input| run(Day09, 9, 1, input),
+        reference: |input| {
+            let mut blocks = disk(input);
+            loop {
+                let free = blocks.iter().position(Option::is_none);
+                let last = blocks.iter().rposition(Option::is_some);
+                match (free, last) {
+                    (Some(free), Some(last)) if free < last => blocks.swap(free, last),
+                    _ => break,
+                }
+            }
+            Some(checksum(&blocks))
+        },
+    });
+}
+
+#[test]
+fn day09_part_2() {
+    check(Case {
+        day: 9,
+        size: 21,
+        solve: |input| run(Day09, 9, 2, input),
+        reference: |input| {
+            let mut blocks = disk(input);
+            let files = blocks.iter().flatten().max().map_or(0, |&id| id + 1);
+            for id in (0..files).rev() {
+                let start = blocks.iter().position(|&b| b == Some(id)).unwrap();
+                let size = blocks.iter().filter(|&&b| b == Some(id)).count();
+
+                // the leftmost run of free blocks that fits it, as long as it's to the left
+                let fits = (0..start).find(|&free| free + size <= start && blocks[free..free + size].iter().all(Option::is_none));
+                if let Some(free) = fits {
+                    for i in 0..size {
+                        blocks.swap(free + i, start + i);
+                    }
+                }
+            }
+            Some(checksum(&blocks))
+        },
+    });
+}
+
+#[test]
+fn day13_part_1() {
+    check(Case {
+        day: 13,
+        size: 10,
+        solve: |input| run(Day13, 13, 1, input),
+        reference: |input| {
+            let mut tokens = 0;
+            for machine in input.split("\n\n") {
+                let n = numbers(machine);
+                let [ax, ay, bx, by, px, py] = n[..] else { return None };
+                // which the solver says it doesn't handle
+                if ax * by == ay * bx {
+                    return None;
+                }
+
+                // at most 100 presses of each button
+                let cheapest = (0..=100)
+                    .flat_map(|a| (0..=100).map(move |b| (a, b)))
+                    .filter(|(a, b)| a * ax + b * bx == px && a * ay + b * by == py)
+                    .map(|(a, b)| 3 * a + b)
+                    .min();
+                tokens += cheapest.unwrap_or(0);
+            }
+            Some(tokens.to_string())
+        },
+    });
+}
+
+/// What the day 17 program prints, or `None` if it doesn't halt.
+fn printed(input: &str) -> Option<String> {
+    let (a, b, c, program) = computer(input)?;
+    let output = execute(a, b, c, &program)?;
+    Some(output.iter().map(u64::to_string).collect::<Vec<_>>().join(","))
+}
+
+/// Runs a program until it halts, or gives up with `None` if it looks like it never will.
+fn execute(mut a: u64, mut b: u64, mut c: u64, program: &[u64]) -> Option<Vec<u64>> {
+    let mut output = Vec::new();
+    let mut ip = 0;
+    for _ in 0..100_000 {
+        if ip + 1 >= program.len() {
+            return Some(output);
+        }
+        let literal = program[ip + 1];
+        let combo = match literal {
+            4 => a,
+            5 => b,
+            6 => c,
+            7 => return None,
+            n => n,
+        };
+        match program[ip] {
+            0 => a = divide(a, combo),
+            1 => b ^= literal,
+            2 => b = combo % 8,
+            3 if a != 0 => {
+                ip = literal as usize;
+                continue;
+            }
+            3 => {}
+            4 => b ^= c,
+            5 => output.push(combo % 8),
+            6 => b = divide(a, combo),
+            _ => c = divide(a, combo),
+        }
+        ip += 2;
+    }
+    None
+}
+
+/// `a` divided by 2 to the power of `combo`, which leaves nothing at all once that's more than a u64 can hold.
+fn divide(a: u64, combo: u64) -> u64 {
+    if combo >= 64 {
+        0
+    } else {
+        a >> combo
+    }
+}
+
+fn computer(input: &str) -> Option<(u64, u64, u64, Vec<u64>)> {
+    let (registers, program) = input.split_once("\n\n")?;
+    // read as they are rather than through `numbers`, since a register can hold more than an i64 can
+    let registers: Vec<u64> = registers
+        .lines()
+        .map(|line| line.rsplit(' ').next()?.parse().ok())
+        .collect::<Option<_>>()?;
+    let program = numbers(program).into_iter().map(|n| n as u64).collect();
+    Some((registers[0], registers[1], registers[2], program))
+}
+
+#[test]
+fn day17_part_1() {
+    check(Case {
+        day: 17,
+        size: 20,
+        solve: |input| run(Day17, 17, 1, input),
+        reference: printed,
+    });
+}
+
+/// The generated programs only ever shift by a little, so these shift by 64 and more into each register, where
+/// taking the shift mod 64, as a release build's `>>` does, or stopping at 63 would each leave something behind.
+#[test]
+fn day17_part_1_large_shifts() {
+    for (a, b, c, program) in [(1u64 << 63, 100, 0, "7,5,5,6"), (5, 64, 0, "6,5,5,5"), (5, 0, 64, "0,6,5,4")] {
+        let input = format!("Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {}\n", a, b, c, program);
+        assert_eq!(run(Day17, 17, 1, &input).ok(), printed(&input), "{}", input);
+        assert_eq!(printed(&input).as_deref(), Some("0"));
+    }
+}
+
+#[test]
+fn day17_part_2() {
+    check(Case {
+        day: 17,
+        size: 20,
+        solve: |input| run(Day17, 17, 2, input),
+        reference: |input| {
+            let (_, b, c, program) = computer(input)?;
+
+            // the search below only holds for programs that print one number and drop three bits of A a round, like
+            // the generated ones, so leave anything the shrinking's bent out of that shape alone
+            let shape = [2, 4, 1, 0, 7, 5, 1, 0, 0, 3, 4, 0, 5, 5, 3, 0];
+            let blanks = [3, 7, 11];
+            if program.len() != shape.len() || (0..shape.len()).any(|i| !blanks.contains(&i) && program[i] != shape[i]) {
+                return None;
+            }
+
+            // every A that prints the last n numbers of the program, for n from 1 up, trying all eight ways to
+            // extend each, and running the whole program every time
+            let mut candidates = vec![0];
+            for n in 1..=program.len() {
+                let wanted = &program[program.len() - n..];
+                candidates = candidates
+                    .iter()
+                    .flat_map(|a| (0..8).map(move |bits| a * 8 + bits))
+                    .filter(|&a| execute(a, b, c, &program).is_some_and(|output| output == wanted))
+                    .collect();
+            }
+            candidates.into_iter().filter(|&a| a > 0).min().map(|a| a.to_string())
+        },
+    });
+}